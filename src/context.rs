@@ -0,0 +1,19 @@
+use sea_orm::Value;
+
+/// 请求上下文，由 HTTP 层按请求构造后传给 service / repo
+#[derive(Debug, Clone, Default)]
+pub struct RequestContext {
+    /// 当前操作人，写入审计列 created_by / updated_by
+    pub user: Option<Value>,
+}
+
+impl RequestContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_user<V: Into<Value>>(mut self, user: V) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+}
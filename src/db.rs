@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use sea_orm::{
+    AccessMode, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr,
+    ExecResult, IsolationLevel, QueryResult, Statement, StreamTrait, TransactionError,
+    TransactionTrait,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// 只读查询使用的连接，`ConnectedRepo` / `ConnectedService` 的 `find_*` / `count` 等读方法通过它选择连接
+///
+/// 普通连接和事务连接返回自身；`DbRouter` 返回只读副本。
+/// `Repo` / `Service` 上显式传入连接的方法不经过这里，直接传入 `DbRouter` 时所有语句都走主库
+pub trait ReadConnection: ConnectionTrait + StreamTrait {
+    type Reader: ConnectionTrait + StreamTrait;
+
+    fn reader(&self) -> &Self::Reader;
+}
+
+impl ReadConnection for DatabaseConnection {
+    type Reader = Self;
+
+    fn reader(&self) -> &Self {
+        self
+    }
+}
+
+// 事务内读写都走同一个事务，保证读到自己的写入
+impl ReadConnection for DatabaseTransaction {
+    type Reader = Self;
+
+    fn reader(&self) -> &Self {
+        self
+    }
+}
+
+/// 读写分离路由：写操作和事务走主库，读操作轮询只读副本
+///
+/// 绑定到 `ConnectedService` / `ConnectedRepo` 后按方法自动选择连接；
+/// 显式传连接时可以用 `reader()` 取副本：
+///
+/// ```ignore
+/// let router = DbRouter::new(primary).with_reader(replica1).with_reader(replica2);
+/// let users = GenericService::new(UserRepo::new()).with_connection(router.clone());
+/// users.create(model).await?;                                // 主库
+/// users.find_page(&param).await?;                            // 副本
+/// users.service().find_page(router.reader(), &param).await?; // 副本
+/// users.service().find_by_id(&router, id).await?;            // 主库，刚写入后立即读取
+/// ```
+#[derive(Debug, Clone)]
+pub struct DbRouter {
+    writer: DatabaseConnection,
+    readers: Vec<DatabaseConnection>,
+    next: Arc<AtomicUsize>,
+    // 为 true 时读操作也走主库
+    read_primary: bool,
+}
+
+impl DbRouter {
+    pub fn new(writer: DatabaseConnection) -> Self {
+        Self {
+            writer,
+            readers: Vec::new(),
+            next: Arc::new(AtomicUsize::new(0)),
+            read_primary: false,
+        }
+    }
+
+    /// 添加只读副本，没有副本时读操作走主库
+    pub fn with_reader(mut self, reader: DatabaseConnection) -> Self {
+        self.readers.push(reader);
+        self
+    }
+
+    pub fn writer(&self) -> &DatabaseConnection {
+        &self.writer
+    }
+
+    /// 返回读操作也走主库的路由，用于写入后需要立即读到最新数据的场景
+    pub fn read_your_writes(&self) -> Self {
+        Self {
+            read_primary: true,
+            ..self.clone()
+        }
+    }
+}
+
+impl ReadConnection for DbRouter {
+    type Reader = DatabaseConnection;
+
+    fn reader(&self) -> &DatabaseConnection {
+        if self.read_primary || self.readers.is_empty() {
+            return &self.writer;
+        }
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.readers.len();
+        &self.readers[index]
+    }
+}
+
+// 作为普通连接使用时所有语句都走主库
+#[async_trait]
+impl ConnectionTrait for DbRouter {
+    fn get_database_backend(&self) -> DbBackend {
+        self.writer.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.writer.execute(stmt).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.writer.execute_unprepared(sql).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.writer.query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.writer.query_all(stmt).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.writer.support_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.writer.is_mock_connection()
+    }
+}
+
+impl StreamTrait for DbRouter {
+    type Stream<'a> = <DatabaseConnection as StreamTrait>::Stream<'a>;
+
+    fn stream<'a>(
+        &'a self,
+        stmt: Statement,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        self.writer.stream(stmt)
+    }
+}
+
+// 事务始终在主库上开启
+#[async_trait]
+impl TransactionTrait for DbRouter {
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.writer.begin().await
+    }
+
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.writer
+            .begin_with_config(isolation_level, access_mode)
+            .await
+    }
+
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        self.writer.transaction(callback).await
+    }
+
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        self.writer
+            .transaction_with_config(callback, isolation_level, access_mode)
+            .await
+    }
+}
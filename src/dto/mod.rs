@@ -11,6 +11,7 @@ where
     serializer.serialize_str(&value.to_string())
 }
 
+#[allow(dead_code, clippy::needless_lifetimes)]
 fn serialize_option_i64_as_str<'a, S>(
     option: &'a Option<i64>,
     serializer: S,
@@ -24,6 +25,7 @@ where
     }
 }
 
+#[allow(dead_code)]
 fn deserialize_i64_from_str<'de, D>(deserializer: D) -> Result<i64, D::Error>
 where
     D: Deserializer<'de>,
@@ -33,6 +35,7 @@ where
 }
 
 // 定义一个函数，用于将 JSON 字符串反序列化为 Option<i64>
+#[allow(dead_code)]
 fn deserialize_option_i64_from_str<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
 where
     D: Deserializer<'de>,
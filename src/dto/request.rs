@@ -1,12 +1,341 @@
+use crate::error::{FieldError, FrameworkError};
+use sea_orm::Order;
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::HashSet;
+use std::str::FromStr;
+use strum::{Display, EnumString};
 use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams, Clone)]
 pub struct PageQueryParam {
+    /// 页码，从 0 开始
+    #[serde(default)]
     pub page_num: u64,
+    /// 每页条数，默认 20
+    #[serde(default = "default_page_size")]
     pub page_size: u64,
+    /// 单列排序字段，`sort` 存在时忽略
     pub sort_by: Option<String>,
+    /// 单列排序方向，默认 ASC
     pub sort_direction: Option<Direction>,
+    /// 多列排序，逗号分隔，字段前加 `-` 表示降序，例如 `status,-created_at`
+    #[param(example = "status,-created_at")]
+    #[schema(example = "status,-created_at")]
+    pub sort: Option<String>,
+    /// 为 true 时不执行 COUNT，响应中只返回 `has_next`，适用于大表
+    #[serde(default)]
+    pub skip_total: bool,
+    /// 只返回指定的列，逗号分隔，为空时返回所有列
+    #[param(example = "id,name")]
+    #[schema(example = "id,name")]
+    pub fields: Option<String>,
+}
+
+impl Default for PageQueryParam {
+    fn default() -> Self {
+        Self {
+            page_num: 0,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            sort_by: None,
+            sort_direction: None,
+            sort: None,
+            skip_total: false,
+            fields: None,
+        }
+    }
+}
+
+fn default_page_size() -> u64 {
+    PageQueryParam::DEFAULT_PAGE_SIZE
+}
+
+impl PageQueryParam {
+    /// 未指定 `page_size` 时的每页条数
+    pub const DEFAULT_PAGE_SIZE: u64 = 20;
+
+    /// 解析出排序列表，优先使用 `sort`，否则回退到 `sort_by` + `sort_direction`
+    pub fn sort_keys(&self) -> Vec<SortKey> {
+        if let Some(sort) = self.sort.as_deref().filter(|s| !s.trim().is_empty()) {
+            return SortKey::parse_list(sort);
+        }
+        match self.sort_by.as_deref().map(str::trim) {
+            Some(field) if !field.is_empty() => vec![SortKey {
+                field: field.to_string(),
+                direction: self.sort_direction.unwrap_or(Direction::ASC),
+            }],
+            _ => Vec::new(),
+        }
+    }
+
+    /// 解析出需要返回的列名
+    pub fn field_names(&self) -> Vec<String> {
+        parse_fields(self.fields.as_deref())
+    }
+}
+
+/// 列表查询参数，不分页
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema, IntoParams, Clone)]
+pub struct ListQueryParam {
+    /// 排序，格式同 `PageQueryParam::sort`
+    #[param(example = "status,-created_at")]
+    #[schema(example = "status,-created_at")]
+    pub sort: Option<String>,
+    /// 只返回指定的列，逗号分隔，为空时返回所有列
+    #[param(example = "id,name")]
+    #[schema(example = "id,name")]
+    pub fields: Option<String>,
+}
+
+impl ListQueryParam {
+    pub fn sort_keys(&self) -> Vec<SortKey> {
+        self.sort
+            .as_deref()
+            .map(SortKey::parse_list)
+            .unwrap_or_default()
+    }
+
+    /// 解析出需要返回的列名
+    pub fn field_names(&self) -> Vec<String> {
+        parse_fields(self.fields.as_deref())
+    }
+}
+
+// 逗号分隔的列名，忽略空白和重复项
+fn parse_fields(fields: Option<&str>) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for field in fields.unwrap_or_default().split(',').map(str::trim) {
+        if !field.is_empty() && !names.iter().any(|name| name == field) {
+            names.push(field.to_string());
+        }
+    }
+    names
+}
+
+/// 游标（keyset）分页参数
+#[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams, Clone)]
+pub struct CursorQueryParam {
+    /// 上一次响应返回的 `next_cursor` 或 `prev_cursor`，为空时从第一页开始
+    pub cursor: Option<String>,
+    /// 每页条数
+    pub page_size: u64,
+    /// 排序，格式同 `PageQueryParam::sort`，所有字段必须同一方向，主键自动追加
+    #[param(example = "-created_at")]
+    #[schema(example = "-created_at")]
+    pub sort: Option<String>,
+}
+
+impl CursorQueryParam {
+    pub fn sort_keys(&self) -> Vec<SortKey> {
+        self.sort
+            .as_deref()
+            .map(SortKey::parse_list)
+            .unwrap_or_default()
+    }
+}
+
+/// 单个排序键
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortKey {
+    pub field: String,
+    pub direction: Direction,
+}
+
+impl SortKey {
+    /// 解析 `status,-created_at` 形式的排序串，`-` 前缀为降序，`+` 或无前缀为升序
+    pub fn parse_list(sort: &str) -> Vec<SortKey> {
+        sort.split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| match s.strip_prefix('-') {
+                Some(field) => SortKey {
+                    field: field.trim().to_string(),
+                    direction: Direction::DESC,
+                },
+                None => SortKey {
+                    field: s.trim_start_matches('+').trim().to_string(),
+                    direction: Direction::ASC,
+                },
+            })
+            .collect()
+    }
+}
+
+/// JSON 请求体形式的搜索请求，支持嵌套的 AND / OR / NOT 条件
+///
+/// ```json
+/// {
+///   "page_num": 0,
+///   "page_size": 20,
+///   "sort": "-id",
+///   "filter": {
+///     "op": "and",
+///     "conditions": [
+///       { "op": "in", "field": "status", "values": [1, 2] },
+///       { "op": "or", "conditions": [
+///         { "op": "like", "field": "name", "value": "li" },
+///         { "op": "like", "field": "email", "value": "li" }
+///       ] }
+///     ]
+///   }
+/// }
+/// ```
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct SearchRequest {
+    /// 页码，从 0 开始
+    pub page_num: u64,
+    /// 每页条数
+    pub page_size: u64,
+    /// 排序，格式同 `PageQueryParam::sort`
+    #[schema(example = "status,-created_at")]
+    pub sort: Option<String>,
+    /// 为 true 时不执行 COUNT
+    #[serde(default)]
+    pub skip_total: bool,
+    /// 过滤条件，为空时不过滤
+    pub filter: Option<SearchCondition>,
+}
+
+impl SearchRequest {
+    /// 转换为分页参数，过滤条件需另行编译
+    pub fn page_param(&self) -> PageQueryParam {
+        PageQueryParam {
+            page_num: self.page_num,
+            page_size: self.page_size,
+            sort: self.sort.clone(),
+            skip_total: self.skip_total,
+            ..Default::default()
+        }
+    }
+}
+
+/// 搜索条件树，`op` 区分条件类型
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone, PartialEq)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum SearchCondition {
+    Eq {
+        field: String,
+        value: JsonValue,
+    },
+    Ne {
+        field: String,
+        value: JsonValue,
+    },
+    Gt {
+        field: String,
+        value: JsonValue,
+    },
+    Gte {
+        field: String,
+        value: JsonValue,
+    },
+    Lt {
+        field: String,
+        value: JsonValue,
+    },
+    Lte {
+        field: String,
+        value: JsonValue,
+    },
+    In {
+        field: String,
+        values: Vec<JsonValue>,
+    },
+    /// 闭区间 `[from, to]`
+    Between {
+        field: String,
+        from: JsonValue,
+        to: JsonValue,
+    },
+    /// 包含子串，仅支持文本列
+    Like {
+        field: String,
+        value: String,
+    },
+    IsNull {
+        field: String,
+    },
+    And {
+        conditions: Vec<SearchCondition>,
+    },
+    Or {
+        conditions: Vec<SearchCondition>,
+    },
+    Not {
+        condition: Box<SearchCondition>,
+    },
+}
+
+/// 过滤运算符，对应查询参数 `filter[字段][运算符]=值`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FilterOp {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    /// 包含子串
+    Like,
+    /// 逗号分隔的多个值
+    In,
+    /// 值为 `true` 时匹配 NULL，`false` 时匹配非 NULL
+    Null,
+}
+
+/// 单个过滤条件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterParam {
+    pub field: String,
+    pub op: FilterOp,
+    pub value: String,
+}
+
+impl FilterParam {
+    /// 从查询参数中解析 `filter[name][like]=foo`、`filter[age][gte]=18` 形式的过滤条件，
+    /// 省略运算符时为 `eq`，其他参数忽略
+    pub fn from_query_pairs<I, K, V>(pairs: I) -> Result<Vec<FilterParam>, FrameworkError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        let mut filters = Vec::new();
+        let mut errors = Vec::new();
+        for (key, value) in pairs {
+            let key = key.as_ref();
+            let Some(rest) = key.strip_prefix("filter[") else {
+                continue;
+            };
+            match Self::parse_key(rest) {
+                Some((field, op)) => filters.push(FilterParam {
+                    field: field.to_string(),
+                    op,
+                    value: value.into(),
+                }),
+                None => errors.push(FieldError::new(key, "invalid filter")),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(FrameworkError::Validation { errors });
+        }
+        Ok(filters)
+    }
+
+    // 解析 `field]` 或 `field][op]`
+    fn parse_key(rest: &str) -> Option<(&str, FilterOp)> {
+        let (field, rest) = rest.split_once(']')?;
+        if field.is_empty() {
+            return None;
+        }
+        if rest.is_empty() {
+            return Some((field, FilterOp::Eq));
+        }
+        let op = rest.strip_prefix('[')?.strip_suffix(']')?;
+        Some((field, op.parse().ok()?))
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -28,7 +357,80 @@ impl Direction {
     }
 }
 
+impl From<Direction> for Order {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::ASC => Order::Asc,
+            Direction::DESC => Order::Desc,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct IdsReq {
+    /// 逗号分隔的主键列表，例如 `1,2,3`
     pub ids: String,
 }
+
+impl IdsReq {
+    /// 单次请求允许的最大 id 数量
+    pub const MAX_IDS: usize = 1000;
+
+    /// 解析为主键列表，最多 `MAX_IDS` 个
+    pub fn parse<T: FromStr>(&self) -> Result<Vec<T>, FrameworkError> {
+        self.parse_with_limit(Self::MAX_IDS)
+    }
+
+    /// 解析为主键列表，去除空白和重复项；出现无法解析的 id 或超出数量上限时返回校验错误
+    pub fn parse_with_limit<T: FromStr>(&self, limit: usize) -> Result<Vec<T>, FrameworkError> {
+        self.parse_tokens(limit, |token| token.parse().ok())
+    }
+
+    /// 解析两列主键列表，每个 id 以 `:` 分隔两列的值，例如 `1:10,2:20`
+    pub fn parse_pairs<A: FromStr, B: FromStr>(&self) -> Result<Vec<(A, B)>, FrameworkError> {
+        self.parse_tokens(Self::MAX_IDS, |token| {
+            let (a, b) = token.split_once(':')?;
+            Some((a.trim().parse().ok()?, b.trim().parse().ok()?))
+        })
+    }
+
+    fn parse_tokens<T>(
+        &self,
+        limit: usize,
+        parse: impl Fn(&str) -> Option<T>,
+    ) -> Result<Vec<T>, FrameworkError> {
+        // 保持输入顺序去重，去重后的数量超出上限时立即返回，不再处理剩余部分
+        let mut seen = HashSet::new();
+        let mut tokens: Vec<&str> = Vec::new();
+        for token in self.ids.split(',').map(str::trim) {
+            if !seen.insert(token) {
+                continue;
+            }
+            if tokens.len() == limit {
+                return Err(FrameworkError::validation(
+                    "ids",
+                    format!("at most {} ids are allowed", limit),
+                ));
+            }
+            tokens.push(token);
+        }
+        if tokens == [""] {
+            return Err(FrameworkError::validation("ids", "ids must not be empty"));
+        }
+        let mut ids = Vec::with_capacity(tokens.len());
+        let mut invalid = Vec::new();
+        for token in tokens {
+            match parse(token) {
+                Some(id) => ids.push(id),
+                None => invalid.push(token),
+            }
+        }
+        if !invalid.is_empty() {
+            return Err(FrameworkError::validation(
+                "ids",
+                format!("invalid ids: {:?}", invalid),
+            ));
+        }
+        Ok(ids)
+    }
+}
@@ -13,12 +13,15 @@ impl MessageResponse {
     }
 }
 
+/// 分页响应，`total` 在跳过 COUNT 时为空，此时由 `has_next` 判断是否还有下一页
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PageResponse<T> {
     pub data: Vec<T>,
     pub page_num: u64,
     pub page_size: u64,
-    pub total: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    pub has_next: bool,
 }
 
 impl<T> PageResponse<T> {
@@ -27,7 +30,23 @@ impl<T> PageResponse<T> {
             data,
             page_num,
             page_size,
-            total,
+            total: Some(total),
+            has_next: page_num.saturating_add(1).saturating_mul(page_size) < total,
+        }
+    }
+
+    pub fn without_total(
+        data: Vec<T>,
+        page_num: u64,
+        page_size: u64,
+        has_next: bool,
+    ) -> PageResponse<T> {
+        PageResponse {
+            data,
+            page_num,
+            page_size,
+            total: None,
+            has_next,
         }
     }
 
@@ -41,6 +60,45 @@ impl<T> PageResponse<T> {
             page_num: self.page_num,
             page_size: self.page_size,
             total: self.total,
+            has_next: self.has_next,
+        }
+    }
+}
+
+/// 游标分页响应，`next_cursor` / `prev_cursor` 为空表示没有下一页 / 上一页
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CursorPageResponse<T> {
+    pub data: Vec<T>,
+    pub page_size: u64,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+}
+
+impl<T> CursorPageResponse<T> {
+    pub fn new(
+        data: Vec<T>,
+        page_size: u64,
+        next_cursor: Option<String>,
+        prev_cursor: Option<String>,
+    ) -> CursorPageResponse<T> {
+        CursorPageResponse {
+            data,
+            page_size,
+            next_cursor,
+            prev_cursor,
+        }
+    }
+
+    pub fn map<F, B>(&self, f: F) -> CursorPageResponse<B>
+    where
+        F: FnMut(&T) -> B,
+    {
+        let data: Vec<B> = self.data.iter().map(f).collect();
+        CursorPageResponse {
+            data,
+            page_size: self.page_size,
+            next_cursor: self.next_cursor.clone(),
+            prev_cursor: self.prev_cursor.clone(),
         }
     }
 }
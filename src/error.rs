@@ -0,0 +1,121 @@
+use sea_orm::{DbErr, SqlErr};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::repo::sort::SortError;
+
+/// 单个字段的校验错误
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new<F: Into<String>, M: Into<String>>(field: F, message: M) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// 框架统一错误类型，Repo / Service 层都返回该错误
+///
+/// 数据库的唯一约束、外键约束错误会被映射为对应的语义变体，
+/// HTTP 层可以直接据此返回 404 / 409 / 422 等状态码
+#[derive(Debug)]
+pub enum FrameworkError {
+    /// 记录不存在
+    NotFound(String),
+    /// 唯一约束冲突等资源状态冲突
+    Conflict(String),
+    /// 乐观锁版本号不匹配，记录已被其他请求修改
+    VersionConflict(String),
+    /// 外键约束冲突（引用的记录不存在或仍被引用）
+    ForeignKeyViolation(String),
+    /// 参数校验失败
+    Validation { errors: Vec<FieldError> },
+    /// 无权操作
+    Forbidden(String),
+    /// 其他数据库错误
+    Database(DbErr),
+}
+
+impl FrameworkError {
+    pub fn not_found<S: Into<String>>(message: S) -> Self {
+        FrameworkError::NotFound(message.into())
+    }
+
+    pub fn conflict<S: Into<String>>(message: S) -> Self {
+        FrameworkError::Conflict(message.into())
+    }
+
+    pub fn forbidden<S: Into<String>>(message: S) -> Self {
+        FrameworkError::Forbidden(message.into())
+    }
+
+    /// 单字段校验错误
+    pub fn validation<F: Into<String>, M: Into<String>>(field: F, message: M) -> Self {
+        FrameworkError::Validation {
+            errors: vec![FieldError::new(field, message)],
+        }
+    }
+}
+
+impl fmt::Display for FrameworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameworkError::NotFound(msg) => write!(f, "not found: {}", msg),
+            FrameworkError::Conflict(msg) => write!(f, "conflict: {}", msg),
+            FrameworkError::VersionConflict(msg) => write!(f, "version conflict: {}", msg),
+            FrameworkError::ForeignKeyViolation(msg) => {
+                write!(f, "foreign key violation: {}", msg)
+            }
+            FrameworkError::Validation { errors } => {
+                write!(f, "validation failed: ")?;
+                for (i, err) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}: {}", err.field, err.message)?;
+                }
+                Ok(())
+            }
+            FrameworkError::Forbidden(msg) => write!(f, "forbidden: {}", msg),
+            FrameworkError::Database(err) => write!(f, "database error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for FrameworkError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FrameworkError::Database(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<DbErr> for FrameworkError {
+    fn from(err: DbErr) -> Self {
+        match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(msg)) => return FrameworkError::Conflict(msg),
+            Some(SqlErr::ForeignKeyConstraintViolation(msg)) => {
+                return FrameworkError::ForeignKeyViolation(msg)
+            }
+            _ => {}
+        }
+        match err {
+            DbErr::RecordNotFound(msg) => FrameworkError::NotFound(msg),
+            DbErr::RecordNotUpdated => FrameworkError::NotFound("record not updated".to_string()),
+            err => FrameworkError::Database(err),
+        }
+    }
+}
+
+impl From<SortError> for FrameworkError {
+    fn from(err: SortError) -> Self {
+        FrameworkError::validation("sort", err.to_string())
+    }
+}
@@ -1,3 +1,3 @@
-// pub mod user_dao;
-// pub mod user_entity;
-// pub mod user_service;
+pub mod user_dao;
+pub mod user_entity;
+pub mod user_service;
@@ -1,17 +1,17 @@
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, DeleteResult, EntityTrait, QueryFilter};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
 
 use crate::{
-    dto::request::PageQueryParam,
+    error::FrameworkError,
     repo::{generic_repo::GenericRepo, repo::Repo},
 };
 
 use super::user_entity;
 
-/// 如果完全复用base实现 可按照下面的写法
+// 如果完全复用base实现 可按照下面的写法
 // pub type UserDao = GenericRepo<user_entity::Entity, i64>;
 
-/// 自定义实现
+/// 自定义实现，只覆盖需要定制的方法，其余方法默认委托给 `generic_dao`
 pub struct UserDao {
     generic_dao: GenericRepo<user_entity::Entity, i64>, // Use the GenericDao for CRUD operations
 }
@@ -24,61 +24,47 @@ impl UserDao {
     }
 
     // 添加自定实现
-    pub async fn find_by_email(
+    pub async fn find_by_email<C>(
         &self,
-        db: &DatabaseConnection,
+        db: &C,
         email: &str,
-    ) -> Result<Option<user_entity::Model>, DbErr> {
-        user_entity::Entity::find()
+    ) -> Result<Option<user_entity::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(user_entity::Entity::find()
             .filter(user_entity::Column::Email.eq(email))
             .one(db)
-            .await
+            .await?)
     }
 }
 
-#[async_trait]
-impl Repo<user_entity::Entity, i64> for UserDao {
-    async fn find_by_id(
-        &self,
-        db: &DatabaseConnection,
-        id: i64,
-    ) -> Result<Option<user_entity::Model>, DbErr> {
-        self.generic_dao.find_by_id(db, id).await
+impl Default for UserDao {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    async fn find_list(&self, db: &DatabaseConnection) -> Result<Vec<user_entity::Model>, DbErr> {
-        self.generic_dao.find_list(db).await
-    }
+#[async_trait]
+impl Repo<user_entity::Entity, i64> for UserDao {
+    type Delegate = GenericRepo<user_entity::Entity, i64>;
 
-    async fn find_page(
-        &self,
-        db: &DatabaseConnection,
-        param: &PageQueryParam,
-    ) -> Result<(Vec<user_entity::Model>, u64), DbErr> {
-        self.generic_dao.find_page(db, param).await
+    fn delegate(&self) -> &Self::Delegate {
+        &self.generic_dao
     }
 
-    async fn create(
+    async fn create<C>(
         &self,
-        db: &DatabaseConnection,
+        db: &C,
         model: user_entity::Model,
-    ) -> Result<user_entity::Model, DbErr> {
+    ) -> Result<user_entity::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
         // Custom implementation if needed
         if model.name.is_empty() {
-            return Err(DbErr::Custom("Name cannot be empty".into()));
+            return Err(FrameworkError::validation("name", "Name cannot be empty"));
         }
         self.generic_dao.create(db, model).await
     }
-
-    async fn update(
-        &self,
-        db: &DatabaseConnection,
-        model: user_entity::Model,
-    ) -> Result<user_entity::Model, DbErr> {
-        self.generic_dao.update(db, model).await
-    }
-
-    async fn delete(&self, db: &DatabaseConnection, id: i64) -> Result<DeleteResult, DbErr> {
-        self.generic_dao.delete(db, id).await
-    }
 }
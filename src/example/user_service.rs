@@ -1,50 +1,43 @@
-use async_trait::async_trait;
-use sea_orm::{DatabaseConnection, DbErr, DeleteResult};
+use sea_orm::ConnectionTrait;
 
-use crate::service::{generic_service::GenericService, service::Service};
+use crate::{error::FrameworkError, service::service::Service};
 
 use super::{user_dao::UserDao, user_entity};
 
+/// 自定义 service，`Service` 的方法默认委托给 `user_dao`
 pub struct UserService {
-    generic_service: GenericService<user_entity::Entity, i64, UserDao>,
+    user_dao: UserDao,
 }
 
 impl UserService {
     pub fn new() -> Self {
-        let user_dao = UserDao::new();
         Self {
-            generic_service: GenericService::new(user_dao),
+            user_dao: UserDao::new(),
         }
     }
-}
 
-#[async_trait]
-impl Service<user_entity::Entity, i64> for UserService {
-    async fn find_by_id(
+    pub async fn find_by_email<C>(
         &self,
-        db: &DatabaseConnection,
-        id: i64,
-    ) -> Result<Option<user_entity::Model>, DbErr> {
-        self.generic_service.find_by_id(db, id).await
+        db: &C,
+        email: &str,
+    ) -> Result<Option<user_entity::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.user_dao.find_by_email(db, email).await
     }
+}
 
-    async fn create(
-        &self,
-        db: &DatabaseConnection,
-        model: user_entity::Model,
-    ) -> Result<user_entity::Model, DbErr> {
-        self.generic_service.create(db, model).await
+impl Default for UserService {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    async fn update(
-        &self,
-        db: &DatabaseConnection,
-        model: user_entity::Model,
-    ) -> Result<user_entity::Model, DbErr> {
-        self.generic_service.update(db, model).await
-    }
+impl Service<user_entity::Entity, i64> for UserService {
+    type Repo = UserDao;
 
-    async fn delete(&self, db: &DatabaseConnection, id: i64) -> Result<DeleteResult, DbErr> {
-        self.generic_service.delete(db, id).await
+    fn repo(&self) -> &UserDao {
+        &self.user_dao
     }
 }
@@ -1,4 +1,7 @@
+pub mod context;
+pub mod db;
 pub mod dto;
-// pub mod example;
+pub mod error;
+pub mod example;
 pub mod repo;
 pub mod service;
@@ -0,0 +1,184 @@
+use sea_orm::prelude::Decimal;
+use sea_orm::sea_query::{Asterisk, ColumnType, Expr, Func, SimpleExpr};
+use sea_orm::{ColumnTrait, QueryResult};
+use serde_json::Value as JsonValue;
+use std::collections::HashSet;
+
+use crate::error::{FieldError, FrameworkError};
+
+/// 聚合函数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggFunc {
+    Count,
+    CountDistinct,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+/// 聚合查询中的一个度量，结果以 `alias` 为列名返回
+///
+/// ```ignore
+/// let rows = repo
+///     .aggregate_json(
+///         &db,
+///         Column::Deleted.eq(false),
+///         vec![Column::Status],
+///         vec![Agg::count("total"), Agg::sum(Column::Amount, "amount")],
+///         // HAVING COUNT(*) > 1
+///         Some(Agg::<Column>::count("total").expr().gt(1).into_condition()),
+///     )
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Agg<C> {
+    func: AggFunc,
+    // COUNT(*) 时为 None
+    column: Option<C>,
+    alias: String,
+}
+
+impl<C> Agg<C>
+where
+    C: ColumnTrait,
+{
+    /// `COUNT(*)`
+    pub fn count<A: Into<String>>(alias: A) -> Self {
+        Self {
+            func: AggFunc::Count,
+            column: None,
+            alias: alias.into(),
+        }
+    }
+
+    /// `COUNT(DISTINCT column)`
+    pub fn count_distinct<A: Into<String>>(column: C, alias: A) -> Self {
+        Self::of(AggFunc::CountDistinct, column, alias)
+    }
+
+    /// `SUM(column)`，仅支持数值列
+    pub fn sum<A: Into<String>>(column: C, alias: A) -> Self {
+        Self::of(AggFunc::Sum, column, alias)
+    }
+
+    /// `AVG(column)`，仅支持数值列
+    pub fn avg<A: Into<String>>(column: C, alias: A) -> Self {
+        Self::of(AggFunc::Avg, column, alias)
+    }
+
+    /// `MIN(column)`
+    pub fn min<A: Into<String>>(column: C, alias: A) -> Self {
+        Self::of(AggFunc::Min, column, alias)
+    }
+
+    /// `MAX(column)`
+    pub fn max<A: Into<String>>(column: C, alias: A) -> Self {
+        Self::of(AggFunc::Max, column, alias)
+    }
+
+    fn of<A: Into<String>>(func: AggFunc, column: C, alias: A) -> Self {
+        Self {
+            func,
+            column: Some(column),
+            alias: alias.into(),
+        }
+    }
+
+    pub fn func(&self) -> AggFunc {
+        self.func
+    }
+
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+
+    /// 聚合表达式，可用于构造 HAVING 条件
+    pub fn expr(&self) -> Expr {
+        let column = match self.column {
+            Some(column) => column.into_expr(),
+            None => Expr::col(Asterisk),
+        };
+        let expr: SimpleExpr = match self.func {
+            AggFunc::Count => column.count(),
+            AggFunc::CountDistinct => column.count_distinct(),
+            AggFunc::Sum => column.sum(),
+            AggFunc::Avg => Func::avg(column).into(),
+            AggFunc::Min => column.min(),
+            AggFunc::Max => column.max(),
+        };
+        Expr::expr(expr)
+    }
+}
+
+// 校验分组列与度量：至少一个度量，别名非空且不与其他别名或分组列重名，SUM/AVG 只能用于数值列
+pub(crate) fn validate<C>(group_by: &[C], measures: &[Agg<C>]) -> Result<(), FrameworkError>
+where
+    C: ColumnTrait,
+{
+    let mut errors = Vec::new();
+    if measures.is_empty() {
+        errors.push(FieldError::new(
+            "measures",
+            "at least one measure is required",
+        ));
+    }
+    let mut names: HashSet<&str> = group_by.iter().map(|column| column.as_str()).collect();
+    for measure in measures {
+        if measure.alias.is_empty() {
+            errors.push(FieldError::new("measures", "alias must not be empty"));
+        } else if !names.insert(&measure.alias) {
+            errors.push(FieldError::new(
+                "measures",
+                format!("duplicate alias: {}", measure.alias),
+            ));
+        }
+        if let (AggFunc::Sum | AggFunc::Avg, Some(column)) = (measure.func, measure.column) {
+            if !is_numeric(column.def().get_column_type()) {
+                errors.push(FieldError::new(
+                    "measures",
+                    format!("{} requires a numeric field", measure.alias),
+                ));
+            }
+        }
+    }
+    if !errors.is_empty() {
+        return Err(FrameworkError::Validation { errors });
+    }
+    Ok(())
+}
+
+fn is_numeric(column_type: &ColumnType) -> bool {
+    matches!(
+        column_type,
+        ColumnType::TinyInteger
+            | ColumnType::SmallInteger
+            | ColumnType::Integer
+            | ColumnType::BigInteger
+            | ColumnType::TinyUnsigned
+            | ColumnType::SmallUnsigned
+            | ColumnType::Unsigned
+            | ColumnType::BigUnsigned
+            | ColumnType::Float
+            | ColumnType::Double
+            | ColumnType::Decimal(_)
+            | ColumnType::Money(_)
+    )
+}
+
+// 读取未能按列类型解码的聚合结果列（SQLite 的表达式列没有声明类型），按整数、浮点、小数、文本依次尝试
+pub(crate) fn measure_json(row: &QueryResult, alias: &str) -> JsonValue {
+    if let Ok(Some(value)) = row.try_get::<Option<i64>>("", alias) {
+        return value.into();
+    }
+    if let Ok(Some(value)) = row.try_get::<Option<f64>>("", alias) {
+        return value.into();
+    }
+    if let Ok(Some(value)) = row.try_get::<Option<Decimal>>("", alias) {
+        return serde_json::from_str(&value.to_string()).unwrap_or(JsonValue::Null);
+    }
+    if let Ok(Some(value)) = row.try_get::<Option<String>>("", alias) {
+        return value.into();
+    }
+    JsonValue::Null
+}
@@ -0,0 +1,169 @@
+use sea_orm::sea_query::{Expr, Keyword, SimpleExpr};
+use sea_orm::{ActiveModelTrait, DbErr, EntityTrait, UpdateMany, Value};
+
+use crate::context::RequestContext;
+
+/// 审计列，未使用的列保持为 None
+#[derive(Debug, Clone, Copy)]
+pub struct AuditColumns<C> {
+    pub created_at: Option<C>,
+    pub updated_at: Option<C>,
+    pub created_by: Option<C>,
+    pub updated_by: Option<C>,
+}
+
+impl<C> Default for AuditColumns<C> {
+    fn default() -> Self {
+        Self {
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            updated_by: None,
+        }
+    }
+}
+
+/// 实体声明审计列，配合 `GenericRepo::with_audit` 在写入时自动填充
+///
+/// ```ignore
+/// impl Auditable for Entity {
+///     fn audit_columns() -> AuditColumns<Column> {
+///         AuditColumns {
+///             created_at: Some(Column::CreatedAt),
+///             updated_at: Some(Column::UpdatedAt),
+///             ..Default::default()
+///         }
+///     }
+/// }
+/// ```
+pub trait Auditable: EntityTrait {
+    fn audit_columns() -> AuditColumns<Self::Column>;
+
+    /// 时间列写入的当前时间，默认为 `DateTimeUtc`，列类型不同时需要覆盖
+    fn audit_now() -> Value {
+        chrono::Utc::now().into()
+    }
+}
+
+/// `GenericRepo` 持有的审计配置
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Audit<C> {
+    columns: AuditColumns<C>,
+    now: fn() -> Value,
+}
+
+impl<C> Audit<C>
+where
+    C: sea_orm::ColumnTrait,
+{
+    pub(crate) fn of<E>() -> Self
+    where
+        E: Auditable<Column = C>,
+    {
+        Self {
+            columns: E::audit_columns(),
+            now: E::audit_now,
+        }
+    }
+
+    /// 所有启用的审计列
+    pub(crate) fn columns(&self) -> Vec<C> {
+        [
+            self.columns.created_at,
+            self.columns.updated_at,
+            self.columns.created_by,
+            self.columns.updated_by,
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// 创建时间及创建人列，更新时不允许覆盖
+    pub(crate) fn created_columns(&self) -> Vec<C> {
+        [self.columns.created_at, self.columns.created_by]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// 新建时填充创建、更新时间及操作人，没有用户时操作人列为 NULL
+    pub(crate) fn stamp_create<A>(&self, model: &mut A, ctx: &RequestContext) -> Result<(), DbErr>
+    where
+        A: ActiveModelTrait,
+        A::Entity: EntityTrait<Column = C>,
+    {
+        let now = (self.now)();
+        for column in [self.columns.created_at, self.columns.updated_at]
+            .into_iter()
+            .flatten()
+        {
+            model.try_set(column, now.clone())?;
+        }
+        for column in [self.columns.created_by, self.columns.updated_by]
+            .into_iter()
+            .flatten()
+        {
+            stamp_user(model, column, ctx)?;
+        }
+        Ok(())
+    }
+
+    /// 更新时填充更新时间及操作人（没有用户时为 NULL），创建列不允许被覆盖
+    pub(crate) fn stamp_update<A>(&self, model: &mut A, ctx: &RequestContext) -> Result<(), DbErr>
+    where
+        A: ActiveModelTrait,
+        A::Entity: EntityTrait<Column = C>,
+    {
+        for column in self.created_columns() {
+            model.not_set(column);
+        }
+        if let Some(column) = self.columns.updated_at {
+            model.try_set(column, (self.now)())?;
+        }
+        if let Some(column) = self.columns.updated_by {
+            stamp_user(model, column, ctx)?;
+        }
+        Ok(())
+    }
+
+    /// 条件更新时追加更新时间及操作人
+    pub(crate) fn stamp_update_many<E>(
+        &self,
+        mut update: UpdateMany<E>,
+        ctx: &RequestContext,
+    ) -> UpdateMany<E>
+    where
+        E: EntityTrait<Column = C>,
+    {
+        if let Some(column) = self.columns.updated_at {
+            update = update.col_expr(column, Expr::value((self.now)()));
+        }
+        if let Some(column) = self.columns.updated_by {
+            let user = match &ctx.user {
+                Some(user) => Expr::value(user.clone()),
+                None => SimpleExpr::Keyword(Keyword::Null),
+            };
+            update = update.col_expr(column, user);
+        }
+        update
+    }
+}
+
+// 操作人列只取自请求上下文，没有用户时清空，不保留客户端提交的值
+fn stamp_user<A>(
+    model: &mut A,
+    column: <A::Entity as EntityTrait>::Column,
+    ctx: &RequestContext,
+) -> Result<(), DbErr>
+where
+    A: ActiveModelTrait,
+{
+    match &ctx.user {
+        Some(user) => model.try_set(column, user.clone()),
+        None => match model.get(column).into_value() {
+            Some(value) => model.try_set(column, value.as_null()),
+            None => Ok(()),
+        },
+    }
+}
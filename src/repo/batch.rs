@@ -0,0 +1,25 @@
+use crate::error::FrameworkError;
+
+/// 批量更新中单行的执行结果
+///
+/// ```ignore
+/// for outcome in repo.update_many(&db, models).await? {
+///     match outcome {
+///         RowOutcome::Updated(model) => { /* 已提交 */ }
+///         RowOutcome::Failed(err) => { /* 导致整批回滚的行 */ }
+///         RowOutcome::RolledBack => { /* 本行执行成功，但因其他行失败被回滚 */ }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub enum RowOutcome<M> {
+    Updated(M),
+    Failed(FrameworkError),
+    RolledBack,
+}
+
+impl<M> RowOutcome<M> {
+    pub fn is_updated(&self) -> bool {
+        matches!(self, RowOutcome::Updated(_))
+    }
+}
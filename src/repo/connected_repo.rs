@@ -0,0 +1,408 @@
+use super::aggregate::Agg;
+use super::batch::RowOutcome;
+use super::repo::Repo;
+use super::upsert::UpsertConflict;
+use crate::context::RequestContext;
+use crate::db::ReadConnection;
+use crate::dto::request::{CursorQueryParam, ListQueryParam, PageQueryParam, SearchRequest};
+use crate::dto::response::{CursorPageResponse, PageResponse};
+use crate::error::FrameworkError;
+use futures::stream::BoxStream;
+use sea_orm::prelude::*;
+use sea_orm::sea_query::IntoCondition;
+use sea_orm::{Condition, DatabaseConnection, DeleteResult, FromQueryResult};
+use sea_orm::{EntityTrait, PrimaryKeyTrait, TransactionTrait};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+// 生成持有连接的类型上与 `Repo` 一一对应的转发方法，`ConnectedRepo` 和 `ConnectedService` 共用
+//
+// `connected_methods!(repo)` 展开为 `self.repo.<方法>(self.reader() 或 self.writer(), 参数..)`，
+// 读写由条目前的 `reader` / `writer` 标记决定，调用方需要提供同名方法并导入方法签名中用到的类型；`Repo` 新增方法时只需在这里补一行
+macro_rules! connected_methods {
+    ($inner:ident) => {
+        $crate::repo::connected_repo::connected_methods! {
+            @emit $inner;
+
+            reader async fn find_by_id(&self, id: Pk) -> Result<Option<E::Model>, FrameworkError>;
+
+            reader async fn find_by_ids(
+                &self,
+                ids: Vec<Pk>,
+            ) -> Result<Vec<E::Model>, FrameworkError>;
+
+            reader async fn find_one_condition<F>(
+                &self,
+                filter: F,
+            ) -> Result<Option<E::Model>, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            reader async fn find_list(&self) -> Result<Vec<E::Model>, FrameworkError>;
+
+            reader async fn find_by_list_condition<F>(
+                &self,
+                filter: F,
+            ) -> Result<Vec<E::Model>, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            reader async fn stream_condition<'a, F>(
+                &'a self,
+                filter: F,
+            ) -> Result<BoxStream<'a, Result<E::Model, FrameworkError>>, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            reader fn stream_chunked_condition<'a, F>(
+                &'a self,
+                filter: F,
+                chunk_size: u64,
+            ) -> BoxStream<'a, Result<E::Model, FrameworkError>>
+            where [F: IntoCondition + Send];
+
+            reader async fn find_list_fields(
+                &self,
+                param: &ListQueryParam,
+            ) -> Result<Vec<JsonValue>, FrameworkError>;
+
+            reader async fn find_list_fields_condition<F>(
+                &self,
+                filter: F,
+                param: &ListQueryParam,
+            ) -> Result<Vec<JsonValue>, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            reader async fn find_page(
+                &self,
+                param: &PageQueryParam,
+            ) -> Result<PageResponse<E::Model>, FrameworkError>;
+
+            reader async fn find_page_condition<F>(
+                &self,
+                filter: F,
+                param: &PageQueryParam,
+            ) -> Result<PageResponse<E::Model>, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            reader async fn find_page_fields(
+                &self,
+                param: &PageQueryParam,
+            ) -> Result<PageResponse<JsonValue>, FrameworkError>;
+
+            reader async fn find_page_fields_condition<F>(
+                &self,
+                filter: F,
+                param: &PageQueryParam,
+            ) -> Result<PageResponse<JsonValue>, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            reader async fn search(
+                &self,
+                request: &SearchRequest,
+            ) -> Result<PageResponse<E::Model>, FrameworkError>;
+
+            reader async fn find_by_id_with<R>(
+                &self,
+                id: Pk,
+                related: R,
+            ) -> Result<Option<(E::Model, Vec<R::Model>)>, FrameworkError>
+            where [R: EntityTrait, R::Model: Send + Sync, E: Related<R>];
+
+            reader async fn find_page_with<R>(
+                &self,
+                param: &PageQueryParam,
+                related: R,
+            ) -> Result<PageResponse<(E::Model, Vec<R::Model>)>, FrameworkError>
+            where [R: EntityTrait, R::Model: Send + Sync, E: Related<R>];
+
+            reader async fn find_page_with_condition<F, R>(
+                &self,
+                filter: F,
+                param: &PageQueryParam,
+                related: R,
+            ) -> Result<PageResponse<(E::Model, Vec<R::Model>)>, FrameworkError>
+            where [F: IntoCondition + Send, R: EntityTrait, R::Model: Send + Sync, E: Related<R>];
+
+            reader async fn find_cursor_page(
+                &self,
+                param: &CursorQueryParam,
+            ) -> Result<CursorPageResponse<E::Model>, FrameworkError>;
+
+            reader async fn find_cursor_page_condition<F>(
+                &self,
+                filter: F,
+                param: &CursorQueryParam,
+            ) -> Result<CursorPageResponse<E::Model>, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            reader async fn count(&self) -> Result<u64, FrameworkError>;
+
+            reader async fn count_condition<F>(&self, filter: F) -> Result<u64, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            reader async fn exists_by_id(&self, id: Pk) -> Result<bool, FrameworkError>;
+
+            reader async fn exists_condition<F>(&self, filter: F) -> Result<bool, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            reader async fn aggregate<F, M>(
+                &self,
+                filter: F,
+                group_by: Vec<E::Column>,
+                measures: Vec<Agg<E::Column>>,
+                having: Option<Condition>,
+            ) -> Result<Vec<M>, FrameworkError>
+            where [F: IntoCondition + Send, M: FromQueryResult + Send + Sync];
+
+            reader async fn aggregate_json<F>(
+                &self,
+                filter: F,
+                group_by: Vec<E::Column>,
+                measures: Vec<Agg<E::Column>>,
+                having: Option<Condition>,
+            ) -> Result<Vec<JsonValue>, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            writer async fn create(&self, model: E::Model) -> Result<E::Model, FrameworkError>;
+
+            writer async fn create_with_context(
+                &self,
+                ctx: &RequestContext,
+                model: E::Model,
+            ) -> Result<E::Model, FrameworkError>;
+
+            writer async fn create_many(&self, models: Vec<E::Model>) -> Result<u64, FrameworkError>;
+
+            writer async fn create_many_with_context(
+                &self,
+                ctx: &RequestContext,
+                models: Vec<E::Model>,
+            ) -> Result<u64, FrameworkError>;
+
+            writer async fn create_many_returning(
+                &self,
+                models: Vec<E::Model>,
+            ) -> Result<Vec<E::Model>, FrameworkError>;
+
+            writer async fn create_many_returning_with_context(
+                &self,
+                ctx: &RequestContext,
+                models: Vec<E::Model>,
+            ) -> Result<Vec<E::Model>, FrameworkError>;
+
+            writer async fn upsert(
+                &self,
+                model: E::Model,
+                conflict: &UpsertConflict<E::Column>,
+            ) -> Result<E::Model, FrameworkError>;
+
+            writer async fn upsert_with_context(
+                &self,
+                ctx: &RequestContext,
+                model: E::Model,
+                conflict: &UpsertConflict<E::Column>,
+            ) -> Result<E::Model, FrameworkError>;
+
+            writer async fn upsert_many(
+                &self,
+                models: Vec<E::Model>,
+                conflict: &UpsertConflict<E::Column>,
+            ) -> Result<u64, FrameworkError>;
+
+            writer async fn upsert_many_with_context(
+                &self,
+                ctx: &RequestContext,
+                models: Vec<E::Model>,
+                conflict: &UpsertConflict<E::Column>,
+            ) -> Result<u64, FrameworkError>;
+
+            writer async fn update(&self, model: E::Model) -> Result<E::Model, FrameworkError>;
+
+            writer async fn update_with_context(
+                &self,
+                ctx: &RequestContext,
+                model: E::Model,
+            ) -> Result<E::Model, FrameworkError>;
+
+            writer async fn update_many(
+                &self,
+                models: Vec<E::Model>,
+            ) -> Result<Vec<RowOutcome<E::Model>>, FrameworkError>
+            where [Db: TransactionTrait];
+
+            writer async fn update_many_with_context(
+                &self,
+                ctx: &RequestContext,
+                models: Vec<E::Model>,
+            ) -> Result<Vec<RowOutcome<E::Model>>, FrameworkError>
+            where [Db: TransactionTrait];
+
+            writer async fn patch(
+                &self,
+                id: Pk,
+                json: JsonValue,
+            ) -> Result<E::Model, FrameworkError>
+            where [E::Model: Serialize + DeserializeOwned];
+
+            writer async fn patch_with_context(
+                &self,
+                ctx: &RequestContext,
+                id: Pk,
+                json: JsonValue,
+            ) -> Result<E::Model, FrameworkError>
+            where [E::Model: Serialize + DeserializeOwned];
+
+            writer async fn patch_active(
+                &self,
+                model: E::ActiveModel,
+            ) -> Result<E::Model, FrameworkError>;
+
+            writer async fn patch_active_with_context(
+                &self,
+                ctx: &RequestContext,
+                model: E::ActiveModel,
+            ) -> Result<E::Model, FrameworkError>;
+
+            writer async fn update_by_condition<F>(
+                &self,
+                filter: F,
+                column_updates: Vec<(E::Column, Value)>,
+            ) -> Result<u64, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            writer async fn update_by_condition_with_context<F>(
+                &self,
+                ctx: &RequestContext,
+                filter: F,
+                column_updates: Vec<(E::Column, Value)>,
+            ) -> Result<u64, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            writer async fn delete(&self, id: Pk) -> Result<DeleteResult, FrameworkError>;
+
+            writer async fn delete_by_ids(
+                &self,
+                ids: Vec<Pk>,
+            ) -> Result<DeleteResult, FrameworkError>;
+
+            writer async fn delete_batch<F>(
+                &self,
+                condition: F,
+            ) -> Result<DeleteResult, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            writer async fn restore(&self, id: Pk) -> Result<u64, FrameworkError>;
+
+            reader async fn find_with_deleted<F>(
+                &self,
+                filter: F,
+            ) -> Result<Vec<E::Model>, FrameworkError>
+            where [F: IntoCondition + Send];
+
+            writer async fn purge(&self, id: Pk) -> Result<DeleteResult, FrameworkError>;
+        }
+    };
+    (@emit $inner:ident;) => {};
+    (
+        @emit $inner:ident;
+        $route:ident async fn $name:ident $(<$($gen:tt),*>)?(
+            &$($lt:lifetime)? $self:ident $(, $arg:ident: $ty:ty)* $(,)?
+        ) -> $ret:ty
+        $(where [$($bound:tt)*])?;
+        $($rest:tt)*
+    ) => {
+        pub async fn $name $(<$($gen),*>)?(&$($lt)? $self $(, $arg: $ty)*) -> $ret
+        $(where $($bound)*)?
+        {
+            $self.$inner.$name($self.$route() $(, $arg)*).await
+        }
+
+        $crate::repo::connected_repo::connected_methods! { @emit $inner; $($rest)* }
+    };
+    (
+        @emit $inner:ident;
+        $route:ident fn $name:ident $(<$($gen:tt),*>)?(
+            &$($lt:lifetime)? $self:ident $(, $arg:ident: $ty:ty)* $(,)?
+        ) -> $ret:ty
+        $(where [$($bound:tt)*])?;
+        $($rest:tt)*
+    ) => {
+        pub fn $name $(<$($gen),*>)?(&$($lt)? $self $(, $arg: $ty)*) -> $ret
+        $(where $($bound)*)?
+        {
+            $self.$inner.$name($self.$route() $(, $arg)*)
+        }
+
+        $crate::repo::connected_repo::connected_methods! { @emit $inner; $($rest)* }
+    };
+}
+
+pub(crate) use connected_methods;
+
+/// 持有连接的 repo，方法与 `Repo` 一一对应但不再需要传入 `db`
+///
+/// 读方法通过 `ReadConnection::reader` 选择连接，绑定 `DbRouter` 时读走副本、写走主库；
+/// 需要事务时通过 `repo()` / `db()` 使用显式传连接的接口：
+///
+/// ```ignore
+/// let users = UserRepo::new().with_soft_delete().with_connection(db.clone());
+/// let user = users.find_by_id(1).await?;
+///
+/// let txn = users.db().begin().await?;
+/// users.repo().create(&txn, model).await?;
+/// txn.commit().await?;
+/// ```
+pub struct ConnectedRepo<E, Pk, D, Db = DatabaseConnection>
+where
+    E: EntityTrait + Send + Sync,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + 'static,
+    D: Repo<E, Pk>,
+{
+    repo: D,
+    db: Db,
+    _entity: std::marker::PhantomData<E>,
+    _pk: std::marker::PhantomData<Pk>,
+}
+
+impl<E, Pk, D, Db> ConnectedRepo<E, Pk, D, Db>
+where
+    E: EntityTrait + Send + Sync,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + 'static,
+    D: Repo<E, Pk>,
+{
+    pub fn new(repo: D, db: Db) -> Self {
+        Self {
+            repo,
+            db,
+            _entity: std::marker::PhantomData,
+            _pk: std::marker::PhantomData,
+        }
+    }
+
+    pub fn repo(&self) -> &D {
+        &self.repo
+    }
+
+    pub fn db(&self) -> &Db {
+        &self.db
+    }
+}
+
+impl<E, Pk, D, Db> ConnectedRepo<E, Pk, D, Db>
+where
+    E: EntityTrait + Send + Sync,
+    E::ActiveModel: Send,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + 'static,
+    D: Repo<E, Pk>,
+    Db: ReadConnection,
+{
+    // 读方法走 `ReadConnection::reader`，传入 `DbRouter` 时读副本
+    fn reader(&self) -> &Db::Reader {
+        self.db.reader()
+    }
+
+    fn writer(&self) -> &Db {
+        &self.db
+    }
+
+    connected_methods!(repo);
+}
@@ -0,0 +1,239 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use sea_orm::prelude::*;
+use sea_orm::sea_query::{IntoIden, ValueTuple};
+use sea_orm::IdenStatic;
+use sea_orm::Identity;
+use serde::{Deserialize, Serialize};
+
+use crate::error::FrameworkError;
+
+/// 游标翻页方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CursorDirection {
+    /// 取游标之后的一页
+    Next,
+    /// 取游标之前的一页
+    Prev,
+}
+
+/// 游标令牌内容，对客户端不透明（base64url 编码的 JSON）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CursorToken {
+    #[serde(rename = "d")]
+    pub direction: CursorDirection,
+    // 生成令牌时使用的排序列，用于拒绝排序条件已变化的令牌
+    #[serde(rename = "k")]
+    pub columns: Vec<String>,
+    #[serde(rename = "v")]
+    pub values: Vec<CursorValue>,
+}
+
+/// 可以放入游标的列值，保留原始类型以便还原成同类型的 `Value`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "t", content = "v")]
+pub enum CursorValue {
+    Bool(bool),
+    TinyInt(i8),
+    SmallInt(i16),
+    Int(i32),
+    BigInt(i64),
+    TinyUnsigned(u8),
+    SmallUnsigned(u16),
+    Unsigned(u32),
+    BigUnsigned(u64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    Char(char),
+    Date(String),
+    Time(String),
+    DateTime(String),
+    DateTimeUtc(String),
+    DateTimeWithTimeZone(String),
+    Uuid(String),
+    Decimal(String),
+}
+
+const DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+impl TryFrom<Value> for CursorValue {
+    type Error = FrameworkError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let null =
+            || FrameworkError::Database(DbErr::Type("cursor column must not be NULL".to_string()));
+        let cursor_value = match value {
+            Value::Bool(v) => CursorValue::Bool(v.ok_or_else(null)?),
+            Value::TinyInt(v) => CursorValue::TinyInt(v.ok_or_else(null)?),
+            Value::SmallInt(v) => CursorValue::SmallInt(v.ok_or_else(null)?),
+            Value::Int(v) => CursorValue::Int(v.ok_or_else(null)?),
+            Value::BigInt(v) => CursorValue::BigInt(v.ok_or_else(null)?),
+            Value::TinyUnsigned(v) => CursorValue::TinyUnsigned(v.ok_or_else(null)?),
+            Value::SmallUnsigned(v) => CursorValue::SmallUnsigned(v.ok_or_else(null)?),
+            Value::Unsigned(v) => CursorValue::Unsigned(v.ok_or_else(null)?),
+            Value::BigUnsigned(v) => CursorValue::BigUnsigned(v.ok_or_else(null)?),
+            Value::Float(v) => CursorValue::Float(v.ok_or_else(null)?),
+            Value::Double(v) => CursorValue::Double(v.ok_or_else(null)?),
+            Value::String(v) => CursorValue::String(*v.ok_or_else(null)?),
+            Value::Char(v) => CursorValue::Char(v.ok_or_else(null)?),
+            Value::ChronoDate(v) => CursorValue::Date(v.ok_or_else(null)?.to_string()),
+            Value::ChronoTime(v) => CursorValue::Time(v.ok_or_else(null)?.to_string()),
+            Value::ChronoDateTime(v) => {
+                CursorValue::DateTime(v.ok_or_else(null)?.format(DATE_TIME_FORMAT).to_string())
+            }
+            Value::ChronoDateTimeUtc(v) => {
+                CursorValue::DateTimeUtc(v.ok_or_else(null)?.to_rfc3339())
+            }
+            Value::ChronoDateTimeWithTimeZone(v) => {
+                CursorValue::DateTimeWithTimeZone(v.ok_or_else(null)?.to_rfc3339())
+            }
+            Value::Uuid(v) => CursorValue::Uuid(v.ok_or_else(null)?.to_string()),
+            Value::Decimal(v) => CursorValue::Decimal(v.ok_or_else(null)?.to_string()),
+            other => {
+                return Err(FrameworkError::Database(DbErr::Type(format!(
+                    "unsupported cursor column value: {:?}",
+                    other
+                ))))
+            }
+        };
+        Ok(cursor_value)
+    }
+}
+
+impl TryFrom<CursorValue> for Value {
+    type Error = FrameworkError;
+
+    fn try_from(value: CursorValue) -> Result<Self, Self::Error> {
+        fn parse<T, E: std::fmt::Display>(r: Result<T, E>) -> Result<T, FrameworkError> {
+            r.map_err(|err| {
+                FrameworkError::validation("cursor", format!("invalid cursor value: {}", err))
+            })
+        }
+        let value = match value {
+            CursorValue::Bool(v) => v.into(),
+            CursorValue::TinyInt(v) => v.into(),
+            CursorValue::SmallInt(v) => v.into(),
+            CursorValue::Int(v) => v.into(),
+            CursorValue::BigInt(v) => v.into(),
+            CursorValue::TinyUnsigned(v) => v.into(),
+            CursorValue::SmallUnsigned(v) => v.into(),
+            CursorValue::Unsigned(v) => v.into(),
+            CursorValue::BigUnsigned(v) => v.into(),
+            CursorValue::Float(v) => v.into(),
+            CursorValue::Double(v) => v.into(),
+            CursorValue::String(v) => v.into(),
+            CursorValue::Char(v) => v.into(),
+            CursorValue::Date(v) => parse(v.parse::<ChronoDate>())?.into(),
+            CursorValue::Time(v) => parse(v.parse::<ChronoTime>())?.into(),
+            CursorValue::DateTime(v) => {
+                parse(ChronoDateTime::parse_from_str(&v, DATE_TIME_FORMAT))?.into()
+            }
+            CursorValue::DateTimeUtc(v) => {
+                let dt = parse(ChronoDateTimeWithTimeZone::parse_from_rfc3339(&v))?;
+                ChronoDateTimeUtc::from(dt).into()
+            }
+            CursorValue::DateTimeWithTimeZone(v) => {
+                parse(ChronoDateTimeWithTimeZone::parse_from_rfc3339(&v))?.into()
+            }
+            CursorValue::Uuid(v) => parse(Uuid::parse_str(&v))?.into(),
+            CursorValue::Decimal(v) => parse(v.parse::<Decimal>())?.into(),
+        };
+        Ok(value)
+    }
+}
+
+impl CursorToken {
+    /// 根据某一行的排序列值生成令牌
+    pub fn from_model<E>(
+        direction: CursorDirection,
+        columns: &[E::Column],
+        model: &E::Model,
+    ) -> Result<Self, FrameworkError>
+    where
+        E: EntityTrait,
+    {
+        let values = columns
+            .iter()
+            .map(|column| CursorValue::try_from(model.get(*column)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            direction,
+            columns: columns.iter().map(|c| c.as_str().to_string()).collect(),
+            values,
+        })
+    }
+
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("cursor token serializes to json");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(token: &str) -> Result<Self, FrameworkError> {
+        let invalid = || FrameworkError::validation("cursor", "invalid cursor");
+        let bytes = URL_SAFE_NO_PAD
+            .decode(token.trim())
+            .map_err(|_| invalid())?;
+        serde_json::from_slice(&bytes).map_err(|_| invalid())
+    }
+
+    /// 校验令牌与当前排序列一致，并还原出用于 keyset 条件的值
+    pub fn into_values<E>(self, columns: &[E::Column]) -> Result<ValueTuple, FrameworkError>
+    where
+        E: EntityTrait,
+    {
+        let same_columns = self.columns.len() == columns.len()
+            && self
+                .columns
+                .iter()
+                .zip(columns)
+                .all(|(name, column)| name == column.as_str());
+        if !same_columns || self.values.len() != columns.len() {
+            return Err(FrameworkError::validation(
+                "cursor",
+                "cursor does not match the requested sort",
+            ));
+        }
+        let values = self
+            .values
+            .into_iter()
+            .map(Value::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(value_tuple(values))
+    }
+}
+
+/// 将列列表转换为 sea-orm 游标使用的 `Identity`
+pub fn identity_of<E>(columns: &[E::Column]) -> Identity
+where
+    E: EntityTrait,
+{
+    let mut idens = columns.iter().map(|column| (*column).into_iden());
+    match columns.len() {
+        1 => Identity::Unary(idens.next().unwrap()),
+        2 => Identity::Binary(idens.next().unwrap(), idens.next().unwrap()),
+        3 => Identity::Ternary(
+            idens.next().unwrap(),
+            idens.next().unwrap(),
+            idens.next().unwrap(),
+        ),
+        _ => Identity::Many(idens.collect()),
+    }
+}
+
+// ValueTuple 的元数需要与 Identity 保持一致
+pub(crate) fn value_tuple(mut values: Vec<Value>) -> ValueTuple {
+    match values.len() {
+        1 => ValueTuple::One(values.remove(0)),
+        2 => {
+            let v2 = values.remove(1);
+            ValueTuple::Two(values.remove(0), v2)
+        }
+        3 => {
+            let v3 = values.remove(2);
+            let v2 = values.remove(1);
+            ValueTuple::Three(values.remove(0), v2, v3)
+        }
+        _ => ValueTuple::Many(values),
+    }
+}
@@ -0,0 +1,282 @@
+use sea_orm::prelude::{Date, DateTime, DateTimeWithTimeZone, Decimal, Time, Uuid};
+use sea_orm::sea_query::{ColumnType, LikeExpr, SimpleExpr};
+use sea_orm::{ColumnTrait, Condition, EntityTrait, IdenStatic, Value};
+use serde_json::Value as JsonValue;
+use std::str::FromStr;
+
+use crate::dto::request::{FilterOp, FilterParam, SearchCondition};
+use crate::error::{FieldError, FrameworkError};
+
+/// 实体声明允许过滤的列，配合 `GenericRepo::with_filters` 使用
+///
+/// ```ignore
+/// impl Filterable for Entity {
+///     fn filterable_columns() -> Vec<Column> {
+///         vec![Column::Name, Column::Status]
+///     }
+/// }
+/// ```
+pub trait Filterable: EntityTrait {
+    fn filterable_columns() -> Vec<Self::Column>;
+}
+
+/// 将过滤条件转换为 `Condition`，多个条件之间为 AND
+///
+/// 字段必须在白名单中，值按列类型解析，解析失败的条件会一并返回校验错误
+pub fn filter_condition<E>(
+    filters: &[FilterParam],
+    whitelist: &[E::Column],
+) -> Result<Condition, FrameworkError>
+where
+    E: EntityTrait,
+{
+    let mut condition = Condition::all();
+    let mut errors = Vec::new();
+    for filter in filters {
+        let field = format!("filter[{}]", filter.field);
+        let Some(column) = whitelist
+            .iter()
+            .find(|column| column.as_str() == filter.field)
+        else {
+            errors.push(FieldError::new(field, "field is not filterable"));
+            continue;
+        };
+        match filter_expr(*column, filter) {
+            Ok(expr) => condition = condition.add(expr),
+            Err(message) => errors.push(FieldError::new(field, message)),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(FrameworkError::Validation { errors });
+    }
+    Ok(condition)
+}
+
+/// 将 `SearchRequest` 的条件树编译为 `Condition`，字段同样受白名单限制
+pub fn search_condition<E>(
+    condition: &SearchCondition,
+    whitelist: &[E::Column],
+) -> Result<Condition, FrameworkError>
+where
+    E: EntityTrait,
+{
+    let mut errors = Vec::new();
+    let condition = compile::<E>(condition, whitelist, &mut errors);
+    if !errors.is_empty() {
+        return Err(FrameworkError::Validation { errors });
+    }
+    Ok(condition)
+}
+
+// 递归编译条件树，错误收集到 errors 中，出错的节点以空条件占位
+fn compile<E>(
+    condition: &SearchCondition,
+    whitelist: &[E::Column],
+    errors: &mut Vec<FieldError>,
+) -> Condition
+where
+    E: EntityTrait,
+{
+    let group = |group: Condition, conditions: &[SearchCondition], errors: &mut Vec<FieldError>| {
+        conditions.iter().fold(group, |group, condition| {
+            group.add(compile::<E>(condition, whitelist, errors))
+        })
+    };
+    let (field, result) = match condition {
+        SearchCondition::And { conditions } => return group(Condition::all(), conditions, errors),
+        SearchCondition::Or { conditions } => return group(Condition::any(), conditions, errors),
+        SearchCondition::Not { condition } => {
+            return compile::<E>(condition, whitelist, errors).not()
+        }
+        SearchCondition::Eq { field, value } => {
+            (field, compare(field, whitelist, value, |c, v| c.eq(v)))
+        }
+        SearchCondition::Ne { field, value } => {
+            (field, compare(field, whitelist, value, |c, v| c.ne(v)))
+        }
+        SearchCondition::Gt { field, value } => {
+            (field, compare(field, whitelist, value, |c, v| c.gt(v)))
+        }
+        SearchCondition::Gte { field, value } => {
+            (field, compare(field, whitelist, value, |c, v| c.gte(v)))
+        }
+        SearchCondition::Lt { field, value } => {
+            (field, compare(field, whitelist, value, |c, v| c.lt(v)))
+        }
+        SearchCondition::Lte { field, value } => {
+            (field, compare(field, whitelist, value, |c, v| c.lte(v)))
+        }
+        SearchCondition::In { field, values } => (
+            field,
+            leaf(field, whitelist, |c| {
+                let values = values
+                    .iter()
+                    .map(|value| json_value(c, value))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(c.is_in(values))
+            }),
+        ),
+        SearchCondition::Between { field, from, to } => (
+            field,
+            leaf(field, whitelist, |c| {
+                Ok(c.between(json_value(c, from)?, json_value(c, to)?))
+            }),
+        ),
+        SearchCondition::Like { field, value } => (
+            field,
+            leaf(field, whitelist, |c| {
+                if !is_text(c.def().get_column_type()) {
+                    return Err("like is only supported on text fields".to_string());
+                }
+                Ok(c.like(contains_pattern(value)))
+            }),
+        ),
+        SearchCondition::IsNull { field } => (field, leaf(field, whitelist, |c| Ok(c.is_null()))),
+    };
+    match result {
+        Ok(expr) => Condition::all().add(expr),
+        Err(message) => {
+            errors.push(FieldError::new(format!("filter.{}", field), message));
+            Condition::all()
+        }
+    }
+}
+
+// 校验字段在白名单中后构造表达式
+fn leaf<C>(
+    field: &str,
+    whitelist: &[C],
+    build: impl FnOnce(C) -> Result<SimpleExpr, String>,
+) -> Result<SimpleExpr, String>
+where
+    C: ColumnTrait,
+{
+    let column = whitelist
+        .iter()
+        .find(|column| column.as_str() == field)
+        .ok_or_else(|| "field is not filterable".to_string())?;
+    build(*column)
+}
+
+// 单值比较条件
+fn compare<C>(
+    field: &str,
+    whitelist: &[C],
+    value: &JsonValue,
+    op: fn(C, Value) -> SimpleExpr,
+) -> Result<SimpleExpr, String>
+where
+    C: ColumnTrait,
+{
+    leaf(field, whitelist, |column| {
+        Ok(op(column, json_value(column, value)?))
+    })
+}
+
+// JSON 值先转为文本，再按列类型解析
+fn json_value<C>(column: C, value: &JsonValue) -> Result<Value, String>
+where
+    C: ColumnTrait,
+{
+    let raw = match value {
+        JsonValue::String(s) => s.clone(),
+        JsonValue::Number(n) => n.to_string(),
+        JsonValue::Bool(b) => b.to_string(),
+        _ => return Err(format!("invalid value: {}", value)),
+    };
+    parse_value(column, &raw).ok_or_else(|| format!("invalid value: {}", value))
+}
+
+fn filter_expr<C>(column: C, filter: &FilterParam) -> Result<Condition, String>
+where
+    C: ColumnTrait,
+{
+    let value = |raw: &str| {
+        parse_value(column, raw.trim()).ok_or_else(|| format!("invalid value: {}", raw))
+    };
+    let expr = match filter.op {
+        FilterOp::Eq => column.eq(value(&filter.value)?),
+        FilterOp::Ne => column.ne(value(&filter.value)?),
+        FilterOp::Gt => column.gt(value(&filter.value)?),
+        FilterOp::Gte => column.gte(value(&filter.value)?),
+        FilterOp::Lt => column.lt(value(&filter.value)?),
+        FilterOp::Lte => column.lte(value(&filter.value)?),
+        FilterOp::Like => {
+            if !is_text(column.def().get_column_type()) {
+                return Err("like is only supported on text fields".to_string());
+            }
+            column.like(contains_pattern(&filter.value))
+        }
+        FilterOp::In => column.is_in(
+            filter
+                .value
+                .split(',')
+                .map(value)
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        FilterOp::Null => match parse_bool(&filter.value) {
+            Some(true) => column.is_null(),
+            Some(false) => column.is_not_null(),
+            None => return Err(format!("invalid value: {}", filter.value)),
+        },
+    };
+    Ok(Condition::all().add(expr))
+}
+
+// 包含子串匹配，转义值中的 `%`、`_` 和 `\`，避免客户端传入的通配符生效
+fn contains_pattern(value: &str) -> LikeExpr {
+    let mut pattern = String::with_capacity(value.len() + 2);
+    pattern.push('%');
+    for ch in value.chars() {
+        if matches!(ch, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(ch);
+    }
+    pattern.push('%');
+    LikeExpr::new(pattern).escape('\\')
+}
+
+fn is_text(column_type: &ColumnType) -> bool {
+    matches!(
+        column_type,
+        ColumnType::Char(_) | ColumnType::String(_) | ColumnType::Text
+    )
+}
+
+fn parse_bool(raw: &str) -> Option<bool> {
+    match raw.trim() {
+        "true" | "1" => Some(true),
+        "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+// 按列类型解析查询参数中的值，未覆盖的类型按字符串绑定
+fn parse_value<C>(column: C, raw: &str) -> Option<Value>
+where
+    C: ColumnTrait,
+{
+    fn parse<T: FromStr + Into<Value>>(raw: &str) -> Option<Value> {
+        raw.parse::<T>().ok().map(Into::into)
+    }
+    match column.def().get_column_type() {
+        ColumnType::TinyInteger
+        | ColumnType::SmallInteger
+        | ColumnType::Integer
+        | ColumnType::BigInteger => parse::<i64>(raw),
+        ColumnType::TinyUnsigned
+        | ColumnType::SmallUnsigned
+        | ColumnType::Unsigned
+        | ColumnType::BigUnsigned => parse::<u64>(raw),
+        ColumnType::Float | ColumnType::Double => parse::<f64>(raw),
+        ColumnType::Decimal(_) | ColumnType::Money(_) => parse::<Decimal>(raw),
+        ColumnType::Boolean => parse_bool(raw).map(Into::into),
+        ColumnType::Date => parse::<Date>(raw),
+        ColumnType::Time => parse::<Time>(raw),
+        ColumnType::DateTime | ColumnType::Timestamp => parse::<DateTime>(raw),
+        ColumnType::TimestampWithTimeZone => parse::<DateTimeWithTimeZone>(raw),
+        ColumnType::Uuid => parse::<Uuid>(raw),
+        _ => Some(raw.to_string().into()),
+    }
+}
@@ -1,21 +1,57 @@
-use crate::dto::request::{Direction, PageQueryParam};
+use crate::context::RequestContext;
+use crate::dto::request::{
+    CursorQueryParam, Direction, FilterParam, ListQueryParam, PageQueryParam, SearchCondition,
+    SearchRequest, SortKey,
+};
+use crate::dto::response::{CursorPageResponse, PageResponse};
+use crate::error::{FieldError, FrameworkError};
 use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use sea_orm::prelude::*;
-use sea_orm::sea_query::IntoCondition;
+use sea_orm::sea_query::{IntoCondition, IntoValueTuple, OnConflict, ValueTuple};
 use sea_orm::{
-    ActiveModelTrait, DatabaseConnection, DbErr, EntityTrait, Order, PaginatorTrait,
-    PrimaryKeyTrait, QueryFilter, QueryOrder,
+    ActiveModelTrait, Condition, ConnectionTrait, EntityTrait, Iterable, Order, PaginatorTrait,
+    PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Select,
+    StreamTrait, TransactionTrait,
 };
-use sea_orm::{DeleteResult, IntoActiveModel};
+use sea_orm::{DbBackend, DeleteResult, FromQueryResult, IntoActiveModel};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
 
+use super::aggregate::{measure_json, validate as validate_aggregate, Agg};
+use super::audit::{Audit, Auditable};
+use super::batch::RowOutcome;
+use super::connected_repo::ConnectedRepo;
+use super::cursor::{identity_of, value_tuple, CursorDirection, CursorToken};
+use super::filter::{filter_condition, search_condition, Filterable};
+use super::projection::Projectable;
 use super::repo::Repo;
+use super::soft_delete::{SoftDelete, SoftDeleteColumn};
+use super::sort::{order_with_tiebreak, resolve_sort_column, with_primary_key, SortError};
+use super::upsert::UpsertConflict;
+use super::version::Versioned;
 
-// 实现一个泛型的 repo
+// 实现一个泛型的 repo，Pk 为主键类型，多列主键使用元组，例如 `(i64, i64)`
 pub struct GenericRepo<E, Pk>
 where
     E: EntityTrait,
-    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
 {
+    // 排序字段别名，例如 "createdAt" -> Column::CreatedAt
+    sort_aliases: Vec<(String, E::Column)>,
+    // 软删除标记列，为空时 delete 为物理删除
+    soft_delete: Option<SoftDeleteColumn<E::Column>>,
+    // 审计列配置，为空时不自动填充
+    audit: Option<Audit<E::Column>>,
+    // 乐观锁版本列，为空时按主键直接更新
+    version: Option<E::Column>,
+    // 允许客户端过滤的列，为空时不接受任何过滤条件
+    filterable: Vec<E::Column>,
+    // 允许客户端通过 fields 选择的列，为空时不接受任何 fields
+    projectable: Vec<E::Column>,
+    // 批量插入时每条语句的最大行数，为空时按后端绑定参数上限计算
+    insert_chunk_size: Option<usize>,
     _entity: std::marker::PhantomData<E>,
     _pk: std::marker::PhantomData<Pk>,
 }
@@ -23,135 +59,1455 @@ where
 impl<E, Pk> GenericRepo<E, Pk>
 where
     E: EntityTrait,
-    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
 {
     pub fn new() -> Self {
         Self {
+            sort_aliases: Vec::new(),
+            soft_delete: None,
+            audit: None,
+            version: None,
+            filterable: Vec::new(),
+            projectable: Vec::new(),
+            insert_chunk_size: None,
             _entity: std::marker::PhantomData,
             _pk: std::marker::PhantomData,
         }
     }
+
+    /// 为排序字段注册别名，客户端可以用别名代替实际列名
+    pub fn with_sort_alias<S: Into<String>>(mut self, alias: S, column: E::Column) -> Self {
+        self.sort_aliases.push((alias.into(), column));
+        self
+    }
+
+    /// 设置 create_many 每条 INSERT 语句的最大行数，仍不会超过后端的绑定参数上限
+    pub fn with_insert_chunk_size(mut self, size: usize) -> Self {
+        self.insert_chunk_size = Some(size.max(1));
+        self
+    }
+
+    /// 将客户端过滤条件转换为 `Condition`，可直接传给 `find_page_condition` 等方法
+    pub fn filter_condition(&self, filters: &[FilterParam]) -> Result<Condition, FrameworkError> {
+        filter_condition::<E>(filters, &self.filterable)
+    }
+
+    /// 将搜索条件树编译为 `Condition`，字段白名单与 `filter_condition` 相同
+    pub fn search_condition(
+        &self,
+        condition: &SearchCondition,
+    ) -> Result<Condition, FrameworkError> {
+        search_condition::<E>(condition, &self.filterable)
+    }
+
+    /// 按白名单解析排序字段，未知字段返回 `SortError`
+    pub fn resolve_sort_column(&self, field: &str) -> Result<E::Column, SortError> {
+        resolve_sort_column::<E>(field, &self.sort_aliases)
+    }
+
+    // 主键等值条件，支持多列主键
+    fn id_condition(id: Pk) -> Condition {
+        let values = id.into().into_value_tuple();
+        E::PrimaryKey::iter()
+            .zip(values)
+            .fold(Condition::all(), |cond, (key, value)| {
+                cond.add(key.into_column().eq(value))
+            })
+    }
+
+    // 批量插入每批的行数：配置值与绑定参数上限 / 列数 取较小者
+    fn insert_chunk_size(&self, backend: DbBackend) -> usize {
+        let max_params = match backend {
+            DbBackend::Sqlite => 32766,
+            DbBackend::MySql | DbBackend::Postgres => 65535,
+        };
+        let columns = E::Column::iter().count().max(1);
+        let limit = (max_params / columns).max(1);
+        self.insert_chunk_size.map_or(limit, |size| size.min(limit))
+    }
+
+    // 主键列表条件
+    fn ids_condition(ids: Vec<Pk>) -> Condition {
+        Self::keys_condition(ids.into_iter().map(|id| id.into().into_value_tuple()))
+    }
+
+    // 主键值列表条件：单列主键使用 IN，多列主键使用行值 (a, b) IN ((..), (..))
+    fn keys_condition<I>(keys: I) -> Condition
+    where
+        I: IntoIterator<Item = ValueTuple>,
+    {
+        let columns: Vec<E::Column> = E::PrimaryKey::iter().map(|key| key.into_column()).collect();
+        match columns.as_slice() {
+            [column] => Condition::all().add(column.is_in(keys.into_iter().flatten())),
+            _ => Condition::all().add(
+                Expr::tuple(columns.iter().map(|column| Expr::col(*column).into())).in_tuples(keys),
+            ),
+        }
+    }
+
+    // 模型的主键值
+    fn model_key(model: &E::Model) -> ValueTuple {
+        value_tuple(
+            E::PrimaryKey::iter()
+                .map(|key| model.get(key.into_column()))
+                .collect(),
+        )
+    }
+
+    // 按冲突目标列的取值定位 upsert 写入的行
+    fn conflict_condition(model: &E::Model, target: &[E::Column]) -> Condition {
+        target.iter().fold(Condition::all(), |cond, column| {
+            cond.add(column.eq(model.get(*column)))
+        })
+    }
+
+    // 冲突时不覆盖审计创建列，启用乐观锁时版本号自增；启用软删除时不更新也不恢复已删除的行
+    fn on_conflict(&self, conflict: &UpsertConflict<E::Column>) -> OnConflict {
+        let mut skip = self
+            .audit
+            .as_ref()
+            .map(|audit| audit.created_columns())
+            .unwrap_or_default();
+        let only_if = self.soft_delete.as_ref().map(|soft_delete| {
+            skip.push(soft_delete.column());
+            soft_delete.not_deleted()
+        });
+        conflict.build::<E>(&skip, self.version, only_if)
+    }
+
+    // 查询默认附加的条件：启用软删除时排除已删除行
+    fn not_deleted(&self) -> Condition {
+        match &self.soft_delete {
+            Some(soft_delete) => Condition::all().add(soft_delete.not_deleted()),
+            None => Condition::all(),
+        }
+    }
+
+    fn soft_delete_column(&self) -> Result<&SoftDeleteColumn<E::Column>, FrameworkError> {
+        self.soft_delete.as_ref().ok_or_else(|| {
+            FrameworkError::Database(DbErr::Custom(
+                "soft delete is not enabled for this repo".to_string(),
+            ))
+        })
+    }
+
+    // 分页及列表查询共用的排序逻辑：白名单排序列 + 主键兜底
+    fn apply_sort(
+        &self,
+        select: Select<E>,
+        keys: Vec<SortKey>,
+    ) -> Result<Select<E>, FrameworkError> {
+        let sorts = keys
+            .into_iter()
+            .map(|key| {
+                let column = self.resolve_sort_column(&key.field)?;
+                Ok((column, Order::from(key.direction)))
+            })
+            .collect::<Result<Vec<_>, FrameworkError>>()?;
+        Ok(order_with_tiebreak(select, &sorts))
+    }
+
+    // 按列名白名单选择返回的列，为空时选择所有列
+    fn select_fields(&self, fields: &[String]) -> Result<Select<E>, FrameworkError> {
+        if fields.is_empty() {
+            return Ok(E::find());
+        }
+        let mut columns = Vec::with_capacity(fields.len());
+        let mut errors = Vec::new();
+        for field in fields {
+            match self
+                .projectable
+                .iter()
+                .find(|column| column.as_str() == field)
+            {
+                Some(column) => columns.push(*column),
+                None => errors.push(FieldError::new(
+                    "fields",
+                    format!("field is not selectable: {}", field),
+                )),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(FrameworkError::Validation { errors });
+        }
+        Ok(E::find().select_only().columns(columns))
+    }
+
+    // 构造分组聚合查询，结果按分组列升序
+    fn aggregate_select<F>(
+        &self,
+        filter: F,
+        group_by: Vec<E::Column>,
+        measures: &[Agg<E::Column>],
+        having: Option<Condition>,
+    ) -> Result<Select<E>, FrameworkError>
+    where
+        F: IntoCondition,
+    {
+        validate_aggregate(&group_by, measures)?;
+        let mut select = E::find()
+            .select_only()
+            .filter(filter)
+            .filter(self.not_deleted());
+        for column in &group_by {
+            select = select.column(*column).group_by(*column);
+        }
+        for measure in measures {
+            select = select.expr_as(measure.expr(), measure.alias());
+        }
+        if let Some(having) = having {
+            select = select.having(having);
+        }
+        for column in group_by {
+            select = select.order_by_asc(column);
+        }
+        Ok(select)
+    }
+
+    // 按分页参数查询一页，M 为结果类型（实体或 JSON）
+    async fn fetch_page<C, M>(
+        db: &C,
+        select: Select<E>,
+        param: &PageQueryParam,
+    ) -> Result<PageResponse<M>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        M: FromQueryResult + Send + Sync,
+    {
+        let offset = Self::page_offset(param.page_num, param.page_size)?;
+        if param.skip_total {
+            // 不统计总数，多取一行判断是否还有下一页
+            let mut models = select
+                .offset(offset)
+                .limit(Self::page_limit(param.page_size, 1)?)
+                .into_model::<M>()
+                .all(db)
+                .await?;
+            let has_next = models.len() as u64 > param.page_size;
+            models.truncate(param.page_size as usize);
+            return Ok(PageResponse::without_total(
+                models,
+                param.page_num,
+                param.page_size,
+                has_next,
+            ));
+        }
+        let paginator = select.into_model::<M>().paginate(db, param.page_size);
+        let items_total = paginator.num_items().await?;
+        let models = paginator.fetch_page(param.page_num).await?;
+        Ok(PageResponse::new(
+            models,
+            param.page_num,
+            param.page_size,
+            items_total,
+        ))
+    }
+
+    // 每页条数必须大于 0，多取 extra 行后不能超出数据库 LIMIT 的范围（有符号 64 位整数）
+    fn page_limit(page_size: u64, extra: u64) -> Result<u64, FrameworkError> {
+        if page_size == 0 {
+            return Err(FrameworkError::validation(
+                "page_size",
+                "must be greater than 0",
+            ));
+        }
+        page_size
+            .checked_add(extra)
+            .filter(|limit| *limit <= i64::MAX as u64)
+            .ok_or_else(|| FrameworkError::validation("page_size", "is too large"))
+    }
+
+    // 页码对应的 OFFSET，溢出时返回校验错误
+    fn page_offset(page_num: u64, page_size: u64) -> Result<u64, FrameworkError> {
+        Self::page_limit(page_size, 0)?;
+        page_num
+            .checked_mul(page_size)
+            .filter(|offset| *offset <= i64::MAX as u64)
+            .ok_or_else(|| FrameworkError::validation("page_num", "is too large"))
+    }
+
+    // 游标分页的 keyset 列：排序列 + 主键，sea-orm 游标要求所有列同一方向
+    fn cursor_columns(
+        &self,
+        param: &CursorQueryParam,
+    ) -> Result<(Vec<E::Column>, Order), FrameworkError> {
+        let keys = param.sort_keys();
+        let direction = keys.first().map(|k| k.direction).unwrap_or(Direction::ASC);
+        if keys.iter().any(|k| k.direction != direction) {
+            return Err(FrameworkError::validation(
+                "sort",
+                "cursor pagination requires all sort fields to share one direction",
+            ));
+        }
+        let columns = keys
+            .iter()
+            .map(|key| self.resolve_sort_column(&key.field).map_err(Into::into))
+            .collect::<Result<Vec<_>, FrameworkError>>()?;
+        // keyset 比较会丢弃 NULL 行，游标中也无法编码 NULL，可空列不能作为游标排序列
+        if let Some(column) = columns.iter().find(|column| column.def().is_null()) {
+            return Err(FrameworkError::validation(
+                "sort",
+                format!(
+                    "nullable field cannot be used for cursor pagination: {}",
+                    column.as_str()
+                ),
+            ));
+        }
+        Ok((with_primary_key::<E>(columns), direction.into()))
+    }
+}
+
+impl<E, Pk> GenericRepo<E, Pk>
+where
+    E: SoftDelete,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
+{
+    /// 启用软删除：delete 改为更新标记列，find_* 自动排除已删除行
+    pub fn with_soft_delete(mut self) -> Self {
+        self.soft_delete = Some(E::soft_delete_column());
+        self
+    }
+}
+
+impl<E, Pk> GenericRepo<E, Pk>
+where
+    E: Auditable,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
+{
+    /// 启用审计列：create / update / update_by_condition 自动填充时间和操作人
+    pub fn with_audit(mut self) -> Self {
+        self.audit = Some(Audit::of::<E>());
+        self
+    }
+}
+
+impl<E, Pk> GenericRepo<E, Pk>
+where
+    E: Versioned,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
+{
+    /// 启用乐观锁：update 校验并递增版本号
+    pub fn with_versioning(mut self) -> Self {
+        self.version = Some(E::version_column());
+        self
+    }
+}
+
+impl<E, Pk> GenericRepo<E, Pk>
+where
+    E: EntityTrait + Send + Sync,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
+    E::Model: Send + Sync + IntoActiveModel<E::ActiveModel>,
+    E::ActiveModel: ActiveModelTrait<Entity = E> + Send + Sync + From<E::Model>,
+{
+    /// 绑定连接，得到不需要逐个方法传入 `db` 的 repo
+    pub fn with_connection<Db>(self, db: Db) -> ConnectedRepo<E, Pk, Self, Db> {
+        ConnectedRepo::new(self, db)
+    }
+}
+
+impl<E, Pk> GenericRepo<E, Pk>
+where
+    E: Filterable,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
+{
+    /// 启用动态过滤：只允许 `Filterable::filterable_columns` 中的列
+    pub fn with_filters(mut self) -> Self {
+        self.filterable = E::filterable_columns();
+        self
+    }
+}
+
+impl<E, Pk> GenericRepo<E, Pk>
+where
+    E: Projectable,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
+{
+    /// 启用字段投影：`fields` 只允许 `Projectable::projectable_columns` 中的列
+    pub fn with_projection(mut self) -> Self {
+        self.projectable = E::projectable_columns();
+        self
+    }
+}
+
+impl<E, Pk> Default for GenericRepo<E, Pk>
+where
+    E: EntityTrait,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, Pk> GenericRepo<E, Pk>
+where
+    E: EntityTrait + Send + Sync,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
+    E::Model: Send + Sync + IntoActiveModel<E::ActiveModel>,
+    E::ActiveModel: ActiveModelTrait<Entity = E> + Send + Sync + From<E::Model>,
+{
+    // 从 ActiveModel 中取主键值构造等值条件，主键缺失时无法定位行
+    fn active_model_id_condition(model: &E::ActiveModel) -> Result<Condition, FrameworkError> {
+        E::PrimaryKey::iter().try_fold(Condition::all(), |cond, key| {
+            let column = key.into_column();
+            match model.get(column).into_value() {
+                Some(value) => Ok(cond.add(column.eq(value))),
+                None => Err(FrameworkError::Database(DbErr::UpdateGetPrimaryKey)),
+            }
+        })
+    }
+
+    // 填充审计列后按批次大小切分，每批对应一条多行 INSERT
+    fn insert_chunks<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        models: Vec<E::Model>,
+    ) -> Result<Vec<Vec<E::ActiveModel>>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        let chunk_size = self.insert_chunk_size(db.get_database_backend());
+        let mut chunks: Vec<Vec<E::ActiveModel>> = Vec::new();
+        for model in models {
+            let mut active_model = E::ActiveModel::from(model);
+            if let Some(audit) = &self.audit {
+                audit.stamp_create(&mut active_model, ctx)?;
+            }
+            match chunks.last_mut() {
+                Some(chunk) if chunk.len() < chunk_size => chunk.push(active_model),
+                _ => chunks.push(vec![active_model]),
+            }
+        }
+        Ok(chunks)
+    }
+
+    // update / patch 共用的写入逻辑：填充审计列，只更新未删除的行，启用乐观锁时校验版本号
+    async fn persist_update<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        mut active_model: E::ActiveModel,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        if let Some(audit) = &self.audit {
+            audit.stamp_update(&mut active_model, ctx)?;
+        }
+        let id_condition = Self::active_model_id_condition(&active_model)?;
+        let current_version = self.version.and_then(|version| {
+            let current_version = active_model.get(version).into_value();
+            active_model.not_set(version);
+            current_version
+        });
+        let mut update = E::update_many()
+            .set(active_model)
+            .filter(id_condition.clone())
+            .filter(self.not_deleted());
+        // 乐观锁：仅当版本号未变化时更新，并将版本号加一；未携带版本号时不校验
+        if let Some(version) = self.version {
+            update = update.col_expr(version, Expr::col(version).add(1));
+            if let Some(current_version) = current_version {
+                update = update.filter(version.eq(current_version));
+            }
+        }
+        let result = update.exec(db).await?;
+        let updated = E::find()
+            .filter(id_condition)
+            .filter(self.not_deleted())
+            .one(db)
+            .await?;
+        match (result.rows_affected, updated) {
+            (0, Some(_)) if self.version.is_some() => Err(FrameworkError::VersionConflict(
+                "record has been modified by another request".to_string(),
+            )),
+            (_, Some(model)) => Ok(model),
+            (_, None) => Err(FrameworkError::not_found("record not found")),
+        }
+    }
 }
 
 #[async_trait]
 impl<E, Pk> Repo<E, Pk> for GenericRepo<E, Pk>
 where
     E: EntityTrait + Send + Sync,
-    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
     E::Model: Send + Sync + IntoActiveModel<E::ActiveModel>,
     E::ActiveModel: ActiveModelTrait<Entity = E> + Send + Sync + From<E::Model>,
 {
-    async fn find_by_id(&self, db: &DatabaseConnection, id: Pk) -> Result<Option<E::Model>, DbErr> {
+    type Delegate = Self;
+
+    fn delegate(&self) -> &Self {
+        self
+    }
+
+    async fn find_by_id<C>(&self, db: &C, id: Pk) -> Result<Option<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
         let id_value = id.into();
-        E::find_by_id(id_value).one(db).await
+        Ok(E::find_by_id(id_value)
+            .filter(self.not_deleted())
+            .one(db)
+            .await?)
+    }
+
+    async fn find_by_ids<C>(&self, db: &C, ids: Vec<Pk>) -> Result<Vec<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(E::find()
+            .filter(Self::ids_condition(ids))
+            .filter(self.not_deleted())
+            .all(db)
+            .await?)
+    }
+
+    async fn find_one_condition<C, F>(
+        &self,
+        db: &C,
+        filter: F,
+    ) -> Result<Option<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        Ok(E::find()
+            .filter(filter)
+            .filter(self.not_deleted())
+            .one(db)
+            .await?)
+    }
+
+    async fn find_list<C>(&self, db: &C) -> Result<Vec<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(E::find().filter(self.not_deleted()).all(db).await?)
+    }
+
+    async fn find_by_list_condition<C, F>(
+        &self,
+        db: &C,
+        filter: F,
+    ) -> Result<Vec<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        Ok(E::find()
+            .filter(filter.into_condition())
+            .filter(self.not_deleted())
+            .all(db)
+            .await?)
+    }
+
+    async fn stream_condition<'a, C, F>(
+        &'a self,
+        db: &'a C,
+        filter: F,
+    ) -> Result<BoxStream<'a, Result<E::Model, FrameworkError>>, FrameworkError>
+    where
+        C: ConnectionTrait + StreamTrait + Send,
+        F: IntoCondition + Send,
+    {
+        let stream = E::find()
+            .filter(filter.into_condition())
+            .filter(self.not_deleted())
+            .stream(db)
+            .await?;
+        Ok(stream.map_err(FrameworkError::from).boxed())
+    }
+
+    fn stream_chunked_condition<'a, C, F>(
+        &'a self,
+        db: &'a C,
+        filter: F,
+        chunk_size: u64,
+    ) -> BoxStream<'a, Result<E::Model, FrameworkError>>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        let condition = filter.into_condition().add(self.not_deleted());
+        let columns = with_primary_key::<E>(Vec::new());
+        let chunk_size = chunk_size.max(1);
+        // 状态为下一批的起始主键，None 表示已读完
+        stream::try_unfold(Some(None), move |after: Option<Option<ValueTuple>>| {
+            let condition = condition.clone();
+            let columns = columns.clone();
+            async move {
+                let Some(after) = after else {
+                    return Ok(None);
+                };
+                let mut cursor = E::find()
+                    .filter(condition)
+                    .cursor_by(identity_of::<E>(&columns));
+                if let Some(after) = after {
+                    cursor.after(after);
+                }
+                let rows = cursor.first(chunk_size).all(db).await?;
+                let Some(last) = rows.last() else {
+                    return Ok(None);
+                };
+                let next = ((rows.len() as u64) == chunk_size)
+                    .then(|| value_tuple(columns.iter().map(|column| last.get(*column)).collect()));
+                Ok::<_, FrameworkError>(Some((rows, next.map(Some))))
+            }
+        })
+        .map_ok(|rows| stream::iter(rows.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+    }
+
+    async fn find_list_fields<C>(
+        &self,
+        db: &C,
+        param: &ListQueryParam,
+    ) -> Result<Vec<JsonValue>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.find_list_fields_condition(db, Condition::all(), param)
+            .await
+    }
+
+    async fn find_list_fields_condition<C, F>(
+        &self,
+        db: &C,
+        filter: F,
+        param: &ListQueryParam,
+    ) -> Result<Vec<JsonValue>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        let select = self
+            .select_fields(&param.field_names())?
+            .filter(filter)
+            .filter(self.not_deleted());
+        let select = self.apply_sort(select, param.sort_keys())?;
+        Ok(select.into_json().all(db).await?)
     }
 
-    async fn find_one_condition<F>(
+    async fn find_page<C>(
         &self,
-        db: &DatabaseConnection,
+        db: &C,
+        param: &PageQueryParam,
+    ) -> Result<PageResponse<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.find_page_condition(db, Condition::all(), param).await
+    }
+
+    async fn find_page_condition<C, F>(
+        &self,
+        db: &C,
         filter: F,
-    ) -> Result<Option<E::Model>, DbErr>
+        param: &PageQueryParam,
+    ) -> Result<PageResponse<E::Model>, FrameworkError>
     where
+        C: ConnectionTrait,
         F: IntoCondition + Send,
     {
-        E::find().filter(filter).one(db).await
+        let select = E::find().filter(filter).filter(self.not_deleted());
+        let select = self.apply_sort(select, param.sort_keys())?;
+        Self::fetch_page(db, select, param).await
     }
 
-    async fn find_list(&self, db: &DatabaseConnection) -> Result<Vec<E::Model>, DbErr> {
-        E::find().all(db).await
+    async fn find_page_fields<C>(
+        &self,
+        db: &C,
+        param: &PageQueryParam,
+    ) -> Result<PageResponse<JsonValue>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.find_page_fields_condition(db, Condition::all(), param)
+            .await
     }
 
-    async fn find_by_list_condition<F>(
+    async fn find_page_fields_condition<C, F>(
         &self,
-        db: &DatabaseConnection,
+        db: &C,
         filter: F,
-    ) -> Result<Vec<E::Model>, DbErr>
+        param: &PageQueryParam,
+    ) -> Result<PageResponse<JsonValue>, FrameworkError>
     where
+        C: ConnectionTrait,
         F: IntoCondition + Send,
     {
-        E::find().filter(filter.into_condition()).all(db).await
+        let select = self
+            .select_fields(&param.field_names())?
+            .filter(filter)
+            .filter(self.not_deleted());
+        let select = self.apply_sort(select, param.sort_keys())?;
+        Self::fetch_page(db, select, param).await
+    }
+
+    async fn search<C>(
+        &self,
+        db: &C,
+        request: &SearchRequest,
+    ) -> Result<PageResponse<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        let condition = match &request.filter {
+            Some(filter) => self.search_condition(filter)?,
+            None => Condition::all(),
+        };
+        self.find_page_condition(db, condition, &request.page_param())
+            .await
+    }
+
+    async fn find_by_id_with<C, R>(
+        &self,
+        db: &C,
+        id: Pk,
+        related: R,
+    ) -> Result<Option<(E::Model, Vec<R::Model>)>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        E: Related<R>,
+    {
+        let mut rows = E::find()
+            .filter(Self::id_condition(id))
+            .filter(self.not_deleted())
+            .find_with_related(related)
+            .all(db)
+            .await?;
+        Ok(rows.pop())
+    }
+
+    async fn find_page_with<C, R>(
+        &self,
+        db: &C,
+        param: &PageQueryParam,
+        related: R,
+    ) -> Result<PageResponse<(E::Model, Vec<R::Model>)>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        E: Related<R>,
+    {
+        self.find_page_with_condition(db, Condition::all(), param, related)
+            .await
     }
 
-    async fn find_page(
+    async fn find_page_with_condition<C, F, R>(
         &self,
-        db: &DatabaseConnection,
+        db: &C,
+        filter: F,
         param: &PageQueryParam,
-    ) -> Result<(Vec<E::Model>, u64), DbErr> {
-        let mut select = E::find();
-        if let Some(sort_by) = &param.sort_by {
-            let order_expr = sea_orm::sea_query::Expr::expr(
-                sea_orm::sea_query::SimpleExpr::Custom(format!("{}", sort_by)),
-            );
-            match param.sort_direction.unwrap_or(Direction::ASC) {
-                Direction::DESC => select = select.order_by(order_expr, Order::Desc),
-                _ => select = select.order_by(order_expr, Order::Asc),
+        related: R,
+    ) -> Result<PageResponse<(E::Model, Vec<R::Model>)>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        E: Related<R>,
+    {
+        // 先分页查询主实体，再按本页主键一次性加载关联行，避免 JOIN 影响分页
+        let page = self.find_page_condition(db, filter, param).await?;
+        let mut loaded = if page.data.is_empty() {
+            Vec::new()
+        } else {
+            let select =
+                E::find().filter(Self::keys_condition(page.data.iter().map(Self::model_key)));
+            order_with_tiebreak(select, &[])
+                .find_with_related(related)
+                .all(db)
+                .await?
+        };
+        Ok(page.map(|model| {
+            let key = Self::model_key(model);
+            let related = loaded
+                .iter()
+                .position(|(loaded, _)| Self::model_key(loaded) == key)
+                .map(|index| loaded.swap_remove(index).1)
+                .unwrap_or_default();
+            (model.clone(), related)
+        }))
+    }
+
+    async fn find_cursor_page<C>(
+        &self,
+        db: &C,
+        param: &CursorQueryParam,
+    ) -> Result<CursorPageResponse<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.find_cursor_page_condition(db, Condition::all(), param)
+            .await
+    }
+
+    async fn find_cursor_page_condition<C, F>(
+        &self,
+        db: &C,
+        filter: F,
+        param: &CursorQueryParam,
+    ) -> Result<CursorPageResponse<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        let (columns, order) = self.cursor_columns(param)?;
+        let token = match param.cursor.as_deref().filter(|s| !s.trim().is_empty()) {
+            Some(cursor) => Some(CursorToken::decode(cursor)?),
+            None => None,
+        };
+        let direction = token
+            .as_ref()
+            .map(|t| t.direction)
+            .unwrap_or(CursorDirection::Next);
+
+        let mut cursor = E::find()
+            .filter(filter)
+            .filter(self.not_deleted())
+            .cursor_by(identity_of::<E>(&columns));
+        if order == Order::Desc {
+            cursor.desc();
+        }
+        let has_token = token.is_some();
+        if let Some(token) = token {
+            let values = token.into_values::<E>(&columns)?;
+            match direction {
+                CursorDirection::Next => cursor.after(values),
+                CursorDirection::Prev => cursor.before(values),
+            };
+        }
+        // 多取一行用于判断是否还有更多数据
+        let limit = Self::page_limit(param.page_size, 1)?;
+        match direction {
+            CursorDirection::Next => cursor.first(limit),
+            CursorDirection::Prev => cursor.last(limit),
+        };
+        let mut models = cursor.all(db).await?;
+
+        let has_more = models.len() as u64 > param.page_size;
+        if has_more {
+            match direction {
+                CursorDirection::Next => models.truncate(param.page_size as usize),
+                CursorDirection::Prev => {
+                    models.remove(0);
+                }
             }
         }
-        let paginator = select.paginate(db, param.page_size);
-        let items_total = paginator.num_items().await.unwrap();
-        let models = paginator.fetch_page(param.page_num).await?;
-        Ok((models, items_total))
+        let (has_next, has_prev) = match direction {
+            CursorDirection::Next => (has_more, has_token),
+            CursorDirection::Prev => (true, has_more),
+        };
+
+        let encode = |direction,
+                      model: Option<&E::Model>|
+         -> Result<Option<String>, FrameworkError> {
+            model
+                .map(|m| CursorToken::from_model::<E>(direction, &columns, m).map(|t| t.encode()))
+                .transpose()
+        };
+        let next_cursor = if has_next {
+            encode(CursorDirection::Next, models.last())?
+        } else {
+            None
+        };
+        let prev_cursor = if has_prev {
+            encode(CursorDirection::Prev, models.first())?
+        } else {
+            None
+        };
+        Ok(CursorPageResponse::new(
+            models,
+            param.page_size,
+            next_cursor,
+            prev_cursor,
+        ))
+    }
+
+    async fn count<C>(&self, db: &C) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.count_condition(db, Condition::all()).await
+    }
+
+    async fn count_condition<C, F>(&self, db: &C, filter: F) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        Ok(E::find()
+            .filter(filter)
+            .filter(self.not_deleted())
+            .count(db)
+            .await?)
+    }
+
+    async fn exists_by_id<C>(&self, db: &C, id: Pk) -> Result<bool, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.exists_condition(db, Self::id_condition(id)).await
+    }
+
+    async fn exists_condition<C, F>(&self, db: &C, filter: F) -> Result<bool, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        // SELECT 1 ... LIMIT 1，不加载整行
+        let row: Option<i32> = E::find()
+            .select_only()
+            .expr(Expr::val(1))
+            .filter(filter)
+            .filter(self.not_deleted())
+            .limit(1)
+            .into_tuple()
+            .one(db)
+            .await?;
+        Ok(row.is_some())
     }
 
-    async fn find_page_condition<F>(
+    async fn aggregate<C, F, M>(
         &self,
-        db: &DatabaseConnection,
+        db: &C,
         filter: F,
-        param: &PageQueryParam,
-    ) -> Result<(Vec<E::Model>, u64), DbErr>
+        group_by: Vec<E::Column>,
+        measures: Vec<Agg<E::Column>>,
+        having: Option<Condition>,
+    ) -> Result<Vec<M>, FrameworkError>
     where
+        C: ConnectionTrait,
         F: IntoCondition + Send,
+        M: FromQueryResult + Send + Sync,
     {
-        let paginator = E::find().filter(filter).paginate(db, param.page_size);
-        let items_total = paginator.num_items().await.unwrap();
-        let models = paginator.fetch_page(param.page_num).await?;
-        Ok((models, items_total))
+        let select = self.aggregate_select(filter, group_by, &measures, having)?;
+        Ok(select.into_model::<M>().all(db).await?)
+    }
+
+    async fn aggregate_json<C, F>(
+        &self,
+        db: &C,
+        filter: F,
+        group_by: Vec<E::Column>,
+        measures: Vec<Agg<E::Column>>,
+        having: Option<Condition>,
+    ) -> Result<Vec<JsonValue>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        let select = self.aggregate_select(filter, group_by, &measures, having)?;
+        let rows = db
+            .query_all(select.build(db.get_database_backend()))
+            .await?;
+        rows.iter()
+            .map(|row| {
+                let mut json = JsonValue::from_query_result(row, "")?;
+                // 只补全解码失败的度量，已按列类型解码的值保持不变
+                for measure in &measures {
+                    if json[measure.alias()].is_null() {
+                        json[measure.alias()] = measure_json(row, measure.alias());
+                    }
+                }
+                Ok(json)
+            })
+            .collect()
+    }
+
+    async fn create<C>(&self, db: &C, model: E::Model) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.create_with_context(db, &RequestContext::default(), model)
+            .await
     }
 
-    async fn create(&self, db: &DatabaseConnection, model: E::Model) -> Result<E::Model, DbErr> {
+    async fn create_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        model: E::Model,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
         // 将 E::Model 转换为 ActiveModel
-        let active_model = E::ActiveModel::from(model);
-        active_model.insert(db).await
+        let mut active_model = E::ActiveModel::from(model);
+        if let Some(audit) = &self.audit {
+            audit.stamp_create(&mut active_model, ctx)?;
+        }
+        Ok(active_model.insert(db).await?)
+    }
+
+    async fn create_many<C>(&self, db: &C, models: Vec<E::Model>) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.create_many_with_context(db, &RequestContext::default(), models)
+            .await
+    }
+
+    async fn create_many_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        models: Vec<E::Model>,
+    ) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        let mut inserted = 0;
+        for chunk in self.insert_chunks(db, ctx, models)? {
+            inserted += E::insert_many(chunk).exec_without_returning(db).await?;
+        }
+        Ok(inserted)
+    }
+
+    async fn create_many_returning<C>(
+        &self,
+        db: &C,
+        models: Vec<E::Model>,
+    ) -> Result<Vec<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.create_many_returning_with_context(db, &RequestContext::default(), models)
+            .await
+    }
+
+    async fn create_many_returning_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        models: Vec<E::Model>,
+    ) -> Result<Vec<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        if !db.support_returning() {
+            return Err(FrameworkError::Database(DbErr::Custom(
+                "database backend does not support RETURNING".to_string(),
+            )));
+        }
+        let mut inserted = Vec::with_capacity(models.len());
+        for chunk in self.insert_chunks(db, ctx, models)? {
+            inserted.extend(E::insert_many(chunk).exec_with_returning_many(db).await?);
+        }
+        Ok(inserted)
+    }
+
+    async fn upsert<C>(
+        &self,
+        db: &C,
+        model: E::Model,
+        conflict: &UpsertConflict<E::Column>,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.upsert_with_context(db, &RequestContext::default(), model, conflict)
+            .await
+    }
+
+    async fn upsert_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        model: E::Model,
+        conflict: &UpsertConflict<E::Column>,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        // 冲突时可能更新的是已有行，按冲突目标列重新查询而不依赖 RETURNING
+        let target = Self::conflict_condition(&model, conflict.target());
+        let mut active_model = E::ActiveModel::from(model);
+        if let Some(audit) = &self.audit {
+            audit.stamp_create(&mut active_model, ctx)?;
+        }
+        E::insert(active_model)
+            .on_conflict(self.on_conflict(conflict))
+            .exec_without_returning(db)
+            .await?;
+        E::find()
+            .filter(target)
+            .filter(self.not_deleted())
+            .one(db)
+            .await?
+            .ok_or_else(|| FrameworkError::not_found("record not found"))
+    }
+
+    async fn upsert_many<C>(
+        &self,
+        db: &C,
+        models: Vec<E::Model>,
+        conflict: &UpsertConflict<E::Column>,
+    ) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.upsert_many_with_context(db, &RequestContext::default(), models, conflict)
+            .await
+    }
+
+    async fn upsert_many_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        models: Vec<E::Model>,
+        conflict: &UpsertConflict<E::Column>,
+    ) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        if models.is_empty() {
+            return Ok(0);
+        }
+        let mut active_models = Vec::with_capacity(models.len());
+        for model in models {
+            let mut active_model = E::ActiveModel::from(model);
+            if let Some(audit) = &self.audit {
+                audit.stamp_create(&mut active_model, ctx)?;
+            }
+            active_models.push(active_model);
+        }
+        Ok(E::insert_many(active_models)
+            .on_conflict(self.on_conflict(conflict))
+            .exec_without_returning(db)
+            .await?)
+    }
+
+    async fn update<C>(&self, db: &C, model: E::Model) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.update_with_context(db, &RequestContext::default(), model)
+            .await
+    }
+
+    async fn update_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        model: E::Model,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        // into_active_model 得到的字段都是 Unchanged，需要标记为 Set 才会写入
+        let active_model: E::ActiveModel = model.into_active_model().reset_all();
+        self.persist_update(db, ctx, active_model).await
+    }
+
+    async fn update_many<C>(
+        &self,
+        db: &C,
+        models: Vec<E::Model>,
+    ) -> Result<Vec<RowOutcome<E::Model>>, FrameworkError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        self.update_many_with_context(db, &RequestContext::default(), models)
+            .await
+    }
+
+    async fn update_many_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        models: Vec<E::Model>,
+    ) -> Result<Vec<RowOutcome<E::Model>>, FrameworkError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let txn = db.begin().await?;
+        let mut outcomes = Vec::with_capacity(models.len());
+        for model in models {
+            // 每行使用独立的保存点，单行失败不影响后续行继续执行并记录结果
+            let savepoint = txn.begin().await?;
+            match self.update_with_context(&savepoint, ctx, model).await {
+                Ok(updated) => {
+                    savepoint.commit().await?;
+                    outcomes.push(RowOutcome::Updated(updated));
+                }
+                Err(err) => {
+                    savepoint.rollback().await?;
+                    outcomes.push(RowOutcome::Failed(err));
+                }
+            }
+        }
+        // 任一行失败时整体回滚，保证批量更新的原子性；原本成功的行标记为已回滚
+        if outcomes.iter().all(RowOutcome::is_updated) {
+            txn.commit().await?;
+            return Ok(outcomes);
+        }
+        txn.rollback().await?;
+        Ok(outcomes
+            .into_iter()
+            .map(|outcome| match outcome {
+                RowOutcome::Updated(_) => RowOutcome::RolledBack,
+                other => other,
+            })
+            .collect())
+    }
+
+    async fn patch<C>(&self, db: &C, id: Pk, json: JsonValue) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+        E::Model: Serialize + DeserializeOwned,
+    {
+        self.patch_with_context(db, &RequestContext::default(), id, json)
+            .await
+    }
+
+    async fn patch_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        id: Pk,
+        json: JsonValue,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+        E::Model: Serialize + DeserializeOwned,
+    {
+        let JsonValue::Object(patch) = json else {
+            return Err(FrameworkError::validation("body", "expected a JSON object"));
+        };
+        let existing = self
+            .find_by_id(db, id)
+            .await?
+            .ok_or_else(|| FrameworkError::not_found("record not found"))?;
+        // 以现有记录为底合并请求字段，set_from_json 需要完整的模型
+        let mut merged = serde_json::to_value(&existing)
+            .map_err(|err| FrameworkError::Database(DbErr::Json(err.to_string())))?;
+        // 请求字段按 serde 名合并，却按列名写入，两者不一致时无法正确对应
+        let serde_matches_columns = merged.as_object().is_some_and(|fields| {
+            fields.len() == E::Column::iter().count()
+                && E::Column::iter().all(|column| fields.contains_key(column.as_str()))
+        });
+        if !serde_matches_columns {
+            return Err(FrameworkError::Database(DbErr::Custom(
+                "patch requires serde field names to match column names".to_string(),
+            )));
+        }
+        let unknown: Vec<FieldError> = patch
+            .keys()
+            .filter(|key| E::Column::iter().all(|column| column.as_str() != key.as_str()))
+            .map(|key| FieldError::new(key.as_str(), "unknown field"))
+            .collect();
+        if !unknown.is_empty() {
+            return Err(FrameworkError::Validation { errors: unknown });
+        }
+        // 启用乐观锁时必须携带客户端读取时的版本号，否则会以刚加载的版本校验，失去锁的意义
+        if let Some(version) = self.version {
+            if !patch.contains_key(version.as_str()) {
+                return Err(FrameworkError::validation(
+                    version.as_str(),
+                    "version is required",
+                ));
+            }
+        }
+
+        if let JsonValue::Object(fields) = &mut merged {
+            fields.extend(patch.clone());
+        }
+        let mut active_model: E::ActiveModel = existing.clone().into_active_model();
+        let mut patched = active_model.clone();
+        patched
+            .set_from_json(merged)
+            .map_err(|err| FrameworkError::validation("body", err.to_string()))?;
+        // 只把请求中出现的非主键列标记为 Set，其余列保持 Unchanged 不参与更新
+        for column in E::Column::iter() {
+            if !patch.contains_key(column.as_str()) || E::PrimaryKey::from_column(column).is_some()
+            {
+                continue;
+            }
+            if let Some(value) = patched.get(column).into_value() {
+                active_model.set(column, value);
+            }
+        }
+        // 没有需要更新的列时直接返回现有记录
+        if !active_model.is_changed() {
+            return Ok(existing);
+        }
+        self.persist_update(db, ctx, active_model).await
     }
 
-    async fn update(&self, db: &DatabaseConnection, model: E::Model) -> Result<E::Model, DbErr> {
-        let active_model: E::ActiveModel = model.into_active_model();
-        active_model.update(db).await
+    async fn patch_active<C>(
+        &self,
+        db: &C,
+        model: E::ActiveModel,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.patch_active_with_context(db, &RequestContext::default(), model)
+            .await
+    }
+
+    async fn patch_active_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        model: E::ActiveModel,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        if let Some(version) = self.version {
+            if model.get(version).into_value().is_none() {
+                return Err(FrameworkError::validation(
+                    version.as_str(),
+                    "version is required",
+                ));
+            }
+        }
+        self.persist_update(db, ctx, model).await
     }
 
-    async fn update_by_condition<F>(
+    async fn update_by_condition<C, F>(
         &self,
-        db: &DatabaseConnection,
+        db: &C,
         filter: F,
         column_updates: Vec<(E::Column, Value)>,
-    ) -> Result<u64, DbErr>
+    ) -> Result<u64, FrameworkError>
     where
+        C: ConnectionTrait,
         F: IntoCondition + Send,
         E: EntityTrait,
     {
-        let mut update_query = E::update_many().filter(filter.into_condition());
+        self.update_by_condition_with_context(
+            db,
+            &RequestContext::default(),
+            filter,
+            column_updates,
+        )
+        .await
+    }
 
+    async fn update_by_condition_with_context<C, F>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        filter: F,
+        column_updates: Vec<(E::Column, Value)>,
+    ) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        let mut update_query = E::update_many()
+            .filter(filter.into_condition())
+            .filter(self.not_deleted());
+
+        // 审计列和版本列由框架维护，忽略调用方传入的值，避免同一列被重复赋值
+        let managed: Vec<E::Column> = self
+            .audit
+            .as_ref()
+            .map(|audit| audit.columns())
+            .unwrap_or_default()
+            .into_iter()
+            .chain(self.version)
+            .collect();
         for (column, value) in column_updates {
-               update_query = update_query.col_expr(column, Expr::value(value));
-           }
-       
-           let result = update_query.exec(db).await?;
-           Ok(result.rows_affected)
+            if managed.iter().any(|c| c.as_str() == column.as_str()) {
+                continue;
+            }
+            update_query = update_query.col_expr(column, Expr::value(value));
+        }
+        if let Some(audit) = &self.audit {
+            update_query = audit.stamp_update_many(update_query, ctx);
+        }
+        if let Some(version) = self.version {
+            update_query = update_query.col_expr(version, Expr::col(version).add(1));
+        }
+
+        let result = update_query.exec(db).await?;
+        Ok(result.rows_affected)
     }
 
-    async fn delete(&self, db: &DatabaseConnection, id: Pk) -> Result<DeleteResult, DbErr> {
-        let id_value = id.into();
-        E::delete_by_id(id_value).exec(db).await
+    async fn delete<C>(&self, db: &C, id: Pk) -> Result<DeleteResult, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delete_batch(db, Self::id_condition(id)).await
+    }
+
+    async fn delete_by_ids<C>(&self, db: &C, ids: Vec<Pk>) -> Result<DeleteResult, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        if ids.is_empty() {
+            return Ok(DeleteResult { rows_affected: 0 });
+        }
+        self.delete_batch(db, Self::ids_condition(ids)).await
+    }
+
+    async fn delete_batch<C, F>(&self, db: &C, condition: F) -> Result<DeleteResult, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        match &self.soft_delete {
+            Some(soft_delete) => {
+                let result = E::update_many()
+                    .col_expr(soft_delete.column(), soft_delete.deleted_value())
+                    .filter(condition)
+                    .filter(soft_delete.not_deleted())
+                    .exec(db)
+                    .await?;
+                Ok(DeleteResult {
+                    rows_affected: result.rows_affected,
+                })
+            }
+            None => Ok(E::delete_many().filter(condition).exec(db).await?),
+        }
     }
 
-    async fn delete_batch<C>(
+    async fn restore<C>(&self, db: &C, id: Pk) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        let soft_delete = self.soft_delete_column()?;
+        let result = E::update_many()
+            .col_expr(soft_delete.column(), soft_delete.restored_value())
+            .filter(Self::id_condition(id))
+            .filter(soft_delete.deleted())
+            .exec(db)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    async fn find_with_deleted<C, F>(
         &self,
-        db: &DatabaseConnection,
-        condition: C,
-    ) -> Result<DeleteResult, DbErr>
+        db: &C,
+        filter: F,
+    ) -> Result<Vec<E::Model>, FrameworkError>
     where
-        C: IntoCondition + Send,
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        Ok(E::find().filter(filter).all(db).await?)
+    }
+
+    async fn purge<C>(&self, db: &C, id: Pk) -> Result<DeleteResult, FrameworkError>
+    where
+        C: ConnectionTrait,
     {
-        E::delete_many().filter(condition).exec(db).await
+        let id_value = id.into();
+        Ok(E::delete_by_id(id_value).exec(db).await?)
     }
 }
@@ -1,2 +1,14 @@
+pub mod aggregate;
+pub mod audit;
+pub mod batch;
+pub mod connected_repo;
+pub mod cursor;
+pub mod filter;
 pub mod generic_repo;
+pub mod projection;
+#[allow(clippy::module_inception)]
 pub mod repo;
+pub mod soft_delete;
+pub mod sort;
+pub mod upsert;
+pub mod version;
@@ -0,0 +1,16 @@
+use sea_orm::EntityTrait;
+
+/// 实体声明允许客户端通过 `fields` 选择返回的列，配合 `GenericRepo::with_projection` 使用
+///
+/// 不在白名单中的列（如密码、令牌）不能被单独投影出来
+///
+/// ```ignore
+/// impl Projectable for Entity {
+///     fn projectable_columns() -> Vec<Column> {
+///         vec![Column::Id, Column::Name, Column::Status]
+///     }
+/// }
+/// ```
+pub trait Projectable: EntityTrait {
+    fn projectable_columns() -> Vec<Self::Column>;
+}
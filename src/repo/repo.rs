@@ -1,84 +1,700 @@
-use crate::dto::request::PageQueryParam;
+use super::aggregate::Agg;
+use super::batch::RowOutcome;
+use super::upsert::UpsertConflict;
+use crate::context::RequestContext;
+use crate::dto::request::{CursorQueryParam, ListQueryParam, PageQueryParam, SearchRequest};
+use crate::dto::response::{CursorPageResponse, PageResponse};
+use crate::error::FrameworkError;
 use async_trait::async_trait;
+use futures::stream::BoxStream;
 use sea_orm::prelude::*;
 use sea_orm::sea_query::IntoCondition;
-use sea_orm::DeleteResult;
-use sea_orm::{DatabaseConnection, DbErr, EntityTrait, PrimaryKeyTrait};
+use sea_orm::{Condition, DeleteResult, FromQueryResult};
+use sea_orm::{ConnectionTrait, EntityTrait, PrimaryKeyTrait, StreamTrait, TransactionTrait};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
 
 /// 定义 Dao Trait，泛型 E 是 Entity 类型，Pk 是主键类型
+///
+/// 所有方法的 `db` 参数都接受任意 `ConnectionTrait`，
+/// 既可以传入 `DatabaseConnection`，也可以传入 `DatabaseTransaction`
+///
+/// 所有方法默认委托给 `delegate()`，自定义 Dao 包装 `GenericRepo` 后只需覆盖需要定制的方法：
+///
+/// ```ignore
+/// pub struct UserDao {
+///     generic_dao: GenericRepo<Entity, i64>,
+/// }
+///
+/// #[async_trait]
+/// impl Repo<Entity, i64> for UserDao {
+///     type Delegate = GenericRepo<Entity, i64>;
+///
+///     fn delegate(&self) -> &Self::Delegate {
+///         &self.generic_dao
+///     }
+///
+///     async fn create<C>(&self, db: &C, model: Model) -> Result<Model, FrameworkError>
+///     where
+///         C: ConnectionTrait,
+///     {
+///         // 自定义逻辑
+///         self.generic_dao.create(db, model).await
+///     }
+/// }
+/// ```
 #[async_trait]
 pub trait Repo<E, Pk>: Send + Sync
 where
     E: EntityTrait + Send + Sync,
-    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + 'static,
 {
+    // 未覆盖的方法委托给的 repo，`GenericRepo` 实现了所有方法，委托给自身
+    type Delegate: Repo<E, Pk> + 'static;
+
+    fn delegate(&self) -> &Self::Delegate;
+
     // 查找某个实体
-    async fn find_by_id(&self, db: &DatabaseConnection, id: Pk) -> Result<Option<E::Model>, DbErr>;
+    async fn find_by_id<C>(&self, db: &C, id: Pk) -> Result<Option<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().find_by_id(db, id).await
+    }
+
+    // 按主键列表批量查询，单列主键使用 IN 查询
+    async fn find_by_ids<C>(&self, db: &C, ids: Vec<Pk>) -> Result<Vec<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().find_by_ids(db, ids).await
+    }
 
     // 条件查询某个实体
-    async fn find_one_condition<F>(
+    async fn find_one_condition<C, F>(
         &self,
-        db: &DatabaseConnection,
+        db: &C,
         filter: F,
-    ) -> Result<Option<E::Model>, DbErr>
+    ) -> Result<Option<E::Model>, FrameworkError>
     where
-        F: IntoCondition + Send;
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate().find_one_condition(db, filter).await
+    }
 
     // 集合查询全量列表
-    async fn find_list(&self, db: &DatabaseConnection) -> Result<Vec<E::Model>, DbErr>;
+    async fn find_list<C>(&self, db: &C) -> Result<Vec<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().find_list(db).await
+    }
 
     // 集合条件查询列表
-    async fn find_by_list_condition<F>(
+    async fn find_by_list_condition<C, F>(
         &self,
-        db: &DatabaseConnection,
+        db: &C,
         filter: F,
-    ) -> Result<Vec<E::Model>, DbErr>
+    ) -> Result<Vec<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate().find_by_list_condition(db, filter).await
+    }
+
+    // 流式查询，逐行读取结果，适用于导出等大结果集
+    async fn stream_condition<'a, C, F>(
+        &'a self,
+        db: &'a C,
+        filter: F,
+    ) -> Result<BoxStream<'a, Result<E::Model, FrameworkError>>, FrameworkError>
+    where
+        C: ConnectionTrait + StreamTrait + Send,
+        F: IntoCondition + Send,
+    {
+        self.delegate().stream_condition(db, filter).await
+    }
+
+    // 按主键顺序分批查询并逐行产出，适用于不支持服务端游标的场景
+    fn stream_chunked_condition<'a, C, F>(
+        &'a self,
+        db: &'a C,
+        filter: F,
+        chunk_size: u64,
+    ) -> BoxStream<'a, Result<E::Model, FrameworkError>>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate()
+            .stream_chunked_condition(db, filter, chunk_size)
+    }
+
+    // 列表查询，只返回 `fields` 指定的列，列须在投影白名单中
+    async fn find_list_fields<C>(
+        &self,
+        db: &C,
+        param: &ListQueryParam,
+    ) -> Result<Vec<JsonValue>, FrameworkError>
     where
-        F: IntoCondition + Send;
+        C: ConnectionTrait,
+    {
+        self.delegate().find_list_fields(db, param).await
+    }
+
+    // 条件列表查询，只返回 `fields` 指定的列，列须在投影白名单中
+    async fn find_list_fields_condition<C, F>(
+        &self,
+        db: &C,
+        filter: F,
+        param: &ListQueryParam,
+    ) -> Result<Vec<JsonValue>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate()
+            .find_list_fields_condition(db, filter, param)
+            .await
+    }
 
     // 分页查询
-    async fn find_page(
+    async fn find_page<C>(
         &self,
-        db: &DatabaseConnection,
+        db: &C,
         param: &PageQueryParam,
-    ) -> Result<(Vec<E::Model>, u64), DbErr>;
+    ) -> Result<PageResponse<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().find_page(db, param).await
+    }
 
     // 分页条件查询
-    async fn find_page_condition<F>(
+    async fn find_page_condition<C, F>(
+        &self,
+        db: &C,
+        filter: F,
+        param: &PageQueryParam,
+    ) -> Result<PageResponse<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate().find_page_condition(db, filter, param).await
+    }
+
+    // 分页查询，只返回 `fields` 指定的列，列须在投影白名单中
+    async fn find_page_fields<C>(
         &self,
-        db: &DatabaseConnection,
+        db: &C,
+        param: &PageQueryParam,
+    ) -> Result<PageResponse<JsonValue>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().find_page_fields(db, param).await
+    }
+
+    // 条件分页查询，只返回 `fields` 指定的列，列须在投影白名单中
+    async fn find_page_fields_condition<C, F>(
+        &self,
+        db: &C,
         filter: F,
         param: &PageQueryParam,
-    ) -> Result<(Vec<E::Model>, u64), DbErr>
+    ) -> Result<PageResponse<JsonValue>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate()
+            .find_page_fields_condition(db, filter, param)
+            .await
+    }
+
+    // 按 `SearchRequest` 的条件树分页搜索
+    async fn search<C>(
+        &self,
+        db: &C,
+        request: &SearchRequest,
+    ) -> Result<PageResponse<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().search(db, request).await
+    }
+
+    // 按主键查询并加载关联实体
+    async fn find_by_id_with<C, R>(
+        &self,
+        db: &C,
+        id: Pk,
+        related: R,
+    ) -> Result<Option<(E::Model, Vec<R::Model>)>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        E: Related<R>,
+    {
+        self.delegate().find_by_id_with(db, id, related).await
+    }
+
+    // 分页查询并批量加载每行的关联实体
+    async fn find_page_with<C, R>(
+        &self,
+        db: &C,
+        param: &PageQueryParam,
+        related: R,
+    ) -> Result<PageResponse<(E::Model, Vec<R::Model>)>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        E: Related<R>,
+    {
+        self.delegate().find_page_with(db, param, related).await
+    }
+
+    // 条件分页查询并批量加载每行的关联实体
+    async fn find_page_with_condition<C, F, R>(
+        &self,
+        db: &C,
+        filter: F,
+        param: &PageQueryParam,
+        related: R,
+    ) -> Result<PageResponse<(E::Model, Vec<R::Model>)>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        E: Related<R>,
+    {
+        self.delegate()
+            .find_page_with_condition(db, filter, param, related)
+            .await
+    }
+
+    // 游标分页查询
+    async fn find_cursor_page<C>(
+        &self,
+        db: &C,
+        param: &CursorQueryParam,
+    ) -> Result<CursorPageResponse<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().find_cursor_page(db, param).await
+    }
+
+    // 游标分页条件查询
+    async fn find_cursor_page_condition<C, F>(
+        &self,
+        db: &C,
+        filter: F,
+        param: &CursorQueryParam,
+    ) -> Result<CursorPageResponse<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate()
+            .find_cursor_page_condition(db, filter, param)
+            .await
+    }
+
+    // 统计实体数量
+    async fn count<C>(&self, db: &C) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().count(db).await
+    }
+
+    // 按条件统计实体数量
+    async fn count_condition<C, F>(&self, db: &C, filter: F) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate().count_condition(db, filter).await
+    }
+
+    // 判断主键对应的实体是否存在
+    async fn exists_by_id<C>(&self, db: &C, id: Pk) -> Result<bool, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().exists_by_id(db, id).await
+    }
+
+    // 判断是否存在满足条件的实体
+    async fn exists_condition<C, F>(&self, db: &C, filter: F) -> Result<bool, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate().exists_condition(db, filter).await
+    }
+
+    // 分组聚合，结果按分组列升序映射为 M，`having` 可用 `Agg::expr` 构造
+    async fn aggregate<C, F, M>(
+        &self,
+        db: &C,
+        filter: F,
+        group_by: Vec<E::Column>,
+        measures: Vec<Agg<E::Column>>,
+        having: Option<Condition>,
+    ) -> Result<Vec<M>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+        M: FromQueryResult + Send + Sync,
+    {
+        self.delegate()
+            .aggregate(db, filter, group_by, measures, having)
+            .await
+    }
+
+    // 分组聚合并以 JSON 返回，分组列与度量别名作为字段名
+    async fn aggregate_json<C, F>(
+        &self,
+        db: &C,
+        filter: F,
+        group_by: Vec<E::Column>,
+        measures: Vec<Agg<E::Column>>,
+        having: Option<Condition>,
+    ) -> Result<Vec<JsonValue>, FrameworkError>
     where
-        F: IntoCondition + Send;
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate()
+            .aggregate_json(db, filter, group_by, measures, having)
+            .await
+    }
 
     // 创建新实体
-    async fn create(&self, db: &DatabaseConnection, model: E::Model) -> Result<E::Model, DbErr>;
+    async fn create<C>(&self, db: &C, model: E::Model) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().create(db, model).await
+    }
+
+    // 创建新实体，审计列的操作人取自请求上下文
+    async fn create_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        model: E::Model,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().create_with_context(db, ctx, model).await
+    }
+
+    // 批量插入，按批次拆分为多行 INSERT，返回插入的行数
+    async fn create_many<C>(&self, db: &C, models: Vec<E::Model>) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().create_many(db, models).await
+    }
+
+    // 批量插入，审计列的操作人取自请求上下文
+    async fn create_many_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        models: Vec<E::Model>,
+    ) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate()
+            .create_many_with_context(db, ctx, models)
+            .await
+    }
+
+    // 批量插入并返回插入后的实体，仅支持 RETURNING 的后端可用
+    async fn create_many_returning<C>(
+        &self,
+        db: &C,
+        models: Vec<E::Model>,
+    ) -> Result<Vec<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().create_many_returning(db, models).await
+    }
+
+    // 批量插入并返回插入后的实体，审计列的操作人取自请求上下文
+    async fn create_many_returning_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        models: Vec<E::Model>,
+    ) -> Result<Vec<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate()
+            .create_many_returning_with_context(db, ctx, models)
+            .await
+    }
+
+    // 插入或更新：按冲突目标列判断是否已存在
+    async fn upsert<C>(
+        &self,
+        db: &C,
+        model: E::Model,
+        conflict: &UpsertConflict<E::Column>,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().upsert(db, model, conflict).await
+    }
+
+    // 插入或更新，审计列的操作人取自请求上下文
+    async fn upsert_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        model: E::Model,
+        conflict: &UpsertConflict<E::Column>,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate()
+            .upsert_with_context(db, ctx, model, conflict)
+            .await
+    }
+
+    // 批量插入或更新，返回受影响的行数
+    async fn upsert_many<C>(
+        &self,
+        db: &C,
+        models: Vec<E::Model>,
+        conflict: &UpsertConflict<E::Column>,
+    ) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().upsert_many(db, models, conflict).await
+    }
+
+    // 批量插入或更新，审计列的操作人取自请求上下文
+    async fn upsert_many_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        models: Vec<E::Model>,
+        conflict: &UpsertConflict<E::Column>,
+    ) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate()
+            .upsert_many_with_context(db, ctx, models, conflict)
+            .await
+    }
 
     // 更新实体
-    async fn update(&self, db: &DatabaseConnection, model: E::Model) -> Result<E::Model, DbErr>;
+    async fn update<C>(&self, db: &C, model: E::Model) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().update(db, model).await
+    }
+
+    // 更新实体，审计列的操作人取自请求上下文
+    async fn update_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        model: E::Model,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().update_with_context(db, ctx, model).await
+    }
+
+    // 批量更新多条不同的实体，在同一个事务中执行，按输入顺序返回每行的结果；
+    // 任一行失败时整个事务回滚，失败行返回 `RowOutcome::Failed`，其余行返回 `RowOutcome::RolledBack`
+    async fn update_many<C>(
+        &self,
+        db: &C,
+        models: Vec<E::Model>,
+    ) -> Result<Vec<RowOutcome<E::Model>>, FrameworkError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        self.delegate().update_many(db, models).await
+    }
+
+    // 批量更新，审计列的操作人取自请求上下文
+    async fn update_many_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        models: Vec<E::Model>,
+    ) -> Result<Vec<RowOutcome<E::Model>>, FrameworkError>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        self.delegate()
+            .update_many_with_context(db, ctx, models)
+            .await
+    }
+
+    // 局部更新：加载记录后只写入 JSON 中出现的字段；启用乐观锁时 JSON 中必须包含版本列；
+    // JSON 字段名即列名，模型的 serde 字段名与列名不一致（如 rename_all）时返回错误
+    async fn patch<C>(&self, db: &C, id: Pk, json: JsonValue) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+        E::Model: Serialize + DeserializeOwned,
+    {
+        self.delegate().patch(db, id, json).await
+    }
+
+    // 局部更新，审计列的操作人取自请求上下文
+    async fn patch_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        id: Pk,
+        json: JsonValue,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+        E::Model: Serialize + DeserializeOwned,
+    {
+        self.delegate().patch_with_context(db, ctx, id, json).await
+    }
+
+    // 局部更新：只写入 ActiveModel 中为 Set 的列；启用乐观锁时版本列不能为 NotSet
+    async fn patch_active<C>(
+        &self,
+        db: &C,
+        model: E::ActiveModel,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+        E::ActiveModel: Send,
+    {
+        self.delegate().patch_active(db, model).await
+    }
+
+    // 局部更新，审计列的操作人取自请求上下文
+    async fn patch_active_with_context<C>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        model: E::ActiveModel,
+    ) -> Result<E::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+        E::ActiveModel: Send,
+    {
+        self.delegate()
+            .patch_active_with_context(db, ctx, model)
+            .await
+    }
 
     // 条件更新
-    async fn update_by_condition<F>(
+    async fn update_by_condition<C, F>(
         &self,
-        db: &DatabaseConnection,
+        db: &C,
         filter: F,
         column_updates: Vec<(E::Column, Value)>,
-    ) -> Result<u64, DbErr>
+    ) -> Result<u64, FrameworkError>
     where
+        C: ConnectionTrait,
         F: IntoCondition + Send,
-        E: EntityTrait;
+        E: EntityTrait,
+    {
+        self.delegate()
+            .update_by_condition(db, filter, column_updates)
+            .await
+    }
+
+    // 条件更新，审计列的操作人取自请求上下文
+    async fn update_by_condition_with_context<C, F>(
+        &self,
+        db: &C,
+        ctx: &RequestContext,
+        filter: F,
+        column_updates: Vec<(E::Column, Value)>,
+    ) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate()
+            .update_by_condition_with_context(db, ctx, filter, column_updates)
+            .await
+    }
 
     // 删除实体
-    async fn delete(&self, db: &DatabaseConnection, id: Pk) -> Result<DeleteResult, DbErr>;
+    async fn delete<C>(&self, db: &C, id: Pk) -> Result<DeleteResult, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().delete(db, id).await
+    }
+
+    // 按主键列表批量删除，启用软删除时为标记删除
+    async fn delete_by_ids<C>(&self, db: &C, ids: Vec<Pk>) -> Result<DeleteResult, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().delete_by_ids(db, ids).await
+    }
 
     // 批量删除
-    async fn delete_batch<C>(
+    async fn delete_batch<C, F>(&self, db: &C, condition: F) -> Result<DeleteResult, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate().delete_batch(db, condition).await
+    }
+
+    // 恢复软删除的实体
+    async fn restore<C>(&self, db: &C, id: Pk) -> Result<u64, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        self.delegate().restore(db, id).await
+    }
+
+    // 条件查询列表，包含已软删除的行
+    async fn find_with_deleted<C, F>(
         &self,
-        db: &DatabaseConnection,
-        condition: C,
-    ) -> Result<DeleteResult, DbErr>
+        db: &C,
+        filter: F,
+    ) -> Result<Vec<E::Model>, FrameworkError>
+    where
+        C: ConnectionTrait,
+        F: IntoCondition + Send,
+    {
+        self.delegate().find_with_deleted(db, filter).await
+    }
+
+    // 物理删除实体，忽略软删除配置
+    async fn purge<C>(&self, db: &C, id: Pk) -> Result<DeleteResult, FrameworkError>
     where
-        C: IntoCondition + Send;
+        C: ConnectionTrait,
+    {
+        self.delegate().purge(db, id).await
+    }
 }
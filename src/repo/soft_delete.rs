@@ -0,0 +1,67 @@
+use sea_orm::sea_query::{Expr, Keyword, SimpleExpr};
+use sea_orm::{ColumnTrait, EntityTrait};
+
+/// 软删除标记列
+#[derive(Debug, Clone, Copy)]
+pub enum SoftDeleteColumn<C> {
+    /// 可空时间列，NULL 表示未删除，删除时写入当前时间
+    DeletedAt(C),
+    /// 布尔列，false 表示未删除，删除时写入 true
+    IsDeleted(C),
+}
+
+impl<C> SoftDeleteColumn<C>
+where
+    C: ColumnTrait,
+{
+    pub fn column(&self) -> C {
+        match self {
+            SoftDeleteColumn::DeletedAt(column) | SoftDeleteColumn::IsDeleted(column) => *column,
+        }
+    }
+
+    /// 未删除行的过滤条件
+    pub fn not_deleted(&self) -> SimpleExpr {
+        match self {
+            SoftDeleteColumn::DeletedAt(column) => column.is_null(),
+            SoftDeleteColumn::IsDeleted(column) => column.eq(false),
+        }
+    }
+
+    /// 已删除行的过滤条件
+    pub fn deleted(&self) -> SimpleExpr {
+        match self {
+            SoftDeleteColumn::DeletedAt(column) => column.is_not_null(),
+            SoftDeleteColumn::IsDeleted(column) => column.eq(true),
+        }
+    }
+
+    /// 删除时写入标记列的值
+    pub fn deleted_value(&self) -> SimpleExpr {
+        match self {
+            SoftDeleteColumn::DeletedAt(_) => Expr::current_timestamp().into(),
+            SoftDeleteColumn::IsDeleted(_) => Expr::value(true),
+        }
+    }
+
+    /// 恢复时写入标记列的值
+    pub fn restored_value(&self) -> SimpleExpr {
+        match self {
+            SoftDeleteColumn::DeletedAt(_) => SimpleExpr::Keyword(Keyword::Null),
+            SoftDeleteColumn::IsDeleted(_) => Expr::value(false),
+        }
+    }
+}
+
+/// 实体声明软删除列，配合 `GenericRepo::with_soft_delete` 启用软删除
+///
+/// ```ignore
+/// impl SoftDelete for Entity {
+///     fn soft_delete_column() -> SoftDeleteColumn<Column> {
+///         SoftDeleteColumn::DeletedAt(Column::DeletedAt)
+///     }
+/// }
+/// ```
+pub trait SoftDelete: EntityTrait {
+    fn soft_delete_column() -> SoftDeleteColumn<Self::Column>;
+}
@@ -0,0 +1,75 @@
+use sea_orm::{EntityTrait, IdenStatic, Iterable, Order, PrimaryKeyToColumn, QueryOrder, Select};
+use std::fmt;
+
+/// 排序字段不在实体列白名单中
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortError {
+    pub field: String,
+}
+
+impl fmt::Display for SortError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown sort field: {}", self.field)
+    }
+}
+
+impl std::error::Error for SortError {}
+
+/// 将客户端传入的排序字段解析为实体列
+///
+/// 先匹配别名，再匹配 `E::Column` 的列名（`IdenStatic::as_str`），
+/// 都匹配不上时返回 `SortError`，不会把原始文本拼进 SQL
+pub fn resolve_sort_column<E>(
+    field: &str,
+    aliases: &[(String, E::Column)],
+) -> Result<E::Column, SortError>
+where
+    E: EntityTrait,
+{
+    let field = field.trim();
+    if let Some((_, column)) = aliases.iter().find(|(alias, _)| alias == field) {
+        return Ok(*column);
+    }
+    E::Column::iter()
+        .find(|column| column.as_str() == field)
+        .ok_or_else(|| SortError {
+            field: field.to_string(),
+        })
+}
+
+/// 按给定的排序列排序，并追加主键作为稳定的兜底排序
+///
+/// 排序列存在重复值时，仅靠排序列无法保证分页结果稳定，
+/// 追加主键后各页之间不会重叠或漏行
+pub fn order_with_tiebreak<E>(mut select: Select<E>, sorts: &[(E::Column, Order)]) -> Select<E>
+where
+    E: EntityTrait,
+{
+    for (column, order) in sorts {
+        select = select.order_by(*column, order.clone());
+    }
+    for column in missing_primary_key_columns::<E>(sorts.iter().map(|(c, _)| c)) {
+        select = select.order_by(column, Order::Asc);
+    }
+    select
+}
+
+/// 排序列后追加尚未包含的主键列，返回完整的 keyset 列
+pub fn with_primary_key<E>(columns: Vec<E::Column>) -> Vec<E::Column>
+where
+    E: EntityTrait,
+{
+    let missing = missing_primary_key_columns::<E>(columns.iter());
+    columns.into_iter().chain(missing).collect()
+}
+
+fn missing_primary_key_columns<'a, E>(sorted: impl Iterator<Item = &'a E::Column>) -> Vec<E::Column>
+where
+    E: EntityTrait,
+{
+    let sorted: Vec<&str> = sorted.map(|c| c.as_str()).collect();
+    E::PrimaryKey::iter()
+        .map(|key| key.into_column())
+        .filter(|column| !sorted.contains(&column.as_str()))
+        .collect()
+}
@@ -0,0 +1,109 @@
+use sea_orm::sea_query::{Expr, OnConflict, SimpleExpr};
+use sea_orm::{ColumnTrait, EntityTrait, Iterable, PrimaryKeyToColumn};
+
+/// upsert 的冲突目标及冲突时需要更新的列
+///
+/// ```ignore
+/// // 按 email 唯一索引冲突时只更新 name
+/// let conflict = UpsertConflict::columns([Column::Email]).update_columns([Column::Name]);
+/// repo.upsert(&db, model, &conflict).await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct UpsertConflict<C> {
+    target: Vec<C>,
+    // 为 None 时更新除冲突目标和主键以外的所有列
+    update: Option<Vec<C>>,
+}
+
+impl<C> UpsertConflict<C>
+where
+    C: ColumnTrait,
+{
+    /// 以主键为冲突目标
+    pub fn primary_key<E>() -> Self
+    where
+        E: EntityTrait<Column = C>,
+    {
+        Self::columns(E::PrimaryKey::iter().map(|key| key.into_column()))
+    }
+
+    /// 以唯一列组合为冲突目标
+    pub fn columns<I>(target: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+    {
+        Self {
+            target: target.into_iter().collect(),
+            update: None,
+        }
+    }
+
+    /// 冲突时只更新指定列
+    pub fn update_columns<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+    {
+        self.update = Some(columns.into_iter().collect());
+        self
+    }
+
+    /// 冲突时保留已有行，不做任何修改
+    pub fn do_nothing(mut self) -> Self {
+        self.update = Some(Vec::new());
+        self
+    }
+
+    pub fn target(&self) -> &[C] {
+        &self.target
+    }
+
+    /// 生成 `ON CONFLICT` 子句
+    pub fn on_conflict<E>(&self) -> OnConflict
+    where
+        E: EntityTrait<Column = C>,
+    {
+        self.build::<E>(&[], None, None)
+    }
+
+    // skip 中的列冲突时不更新（如审计创建列），version 列冲突时自增；
+    // only_if 不为空时只有已有行满足该条件才更新
+    pub(crate) fn build<E>(
+        &self,
+        skip: &[C],
+        version: Option<C>,
+        only_if: Option<SimpleExpr>,
+    ) -> OnConflict
+    where
+        E: EntityTrait<Column = C>,
+    {
+        let is_primary_key = |column: &C| {
+            E::PrimaryKey::iter().any(|key| key.into_column().as_str() == column.as_str())
+        };
+        let contains =
+            |columns: &[C], column: &C| columns.iter().any(|c| c.as_str() == column.as_str());
+        let excluded = |column: &C| {
+            contains(skip, column) || version.is_some_and(|v| v.as_str() == column.as_str())
+        };
+        let update: Vec<C> = match &self.update {
+            Some(columns) => columns.clone(),
+            None => E::Column::iter()
+                .filter(|column| !contains(&self.target, column) && !is_primary_key(column))
+                .collect(),
+        }
+        .into_iter()
+        .filter(|column| !excluded(column))
+        .collect();
+
+        let mut on_conflict = OnConflict::columns(self.target.clone());
+        if update.is_empty() {
+            on_conflict.do_nothing();
+            return on_conflict;
+        }
+        on_conflict.update_columns(update);
+        if let Some(version) = version {
+            on_conflict.value(version, Expr::col((E::default(), version)).add(1));
+        }
+        on_conflict.action_and_where_option(only_if);
+        on_conflict
+    }
+}
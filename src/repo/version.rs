@@ -0,0 +1,17 @@
+use sea_orm::EntityTrait;
+
+/// 实体声明版本列，配合 `GenericRepo::with_versioning` 启用乐观锁
+///
+/// 启用后 update 会附加 `WHERE version = ?` 并将版本号加一，
+/// 版本号不匹配时返回 `FrameworkError::VersionConflict`
+///
+/// ```ignore
+/// impl Versioned for Entity {
+///     fn version_column() -> Column {
+///         Column::Version
+///     }
+/// }
+/// ```
+pub trait Versioned: EntityTrait {
+    fn version_column() -> Self::Column;
+}
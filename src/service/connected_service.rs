@@ -0,0 +1,91 @@
+use crate::context::RequestContext;
+use crate::db::ReadConnection;
+use crate::dto::request::{CursorQueryParam, ListQueryParam, PageQueryParam, SearchRequest};
+use crate::dto::response::{CursorPageResponse, PageResponse};
+use crate::error::FrameworkError;
+use crate::repo::aggregate::Agg;
+use crate::repo::batch::RowOutcome;
+use crate::repo::upsert::UpsertConflict;
+use futures::stream::BoxStream;
+use sea_orm::prelude::*;
+use sea_orm::{
+    sea_query::IntoCondition, Condition, DatabaseConnection, DeleteResult, EntityTrait,
+    FromQueryResult, PrimaryKeyTrait, TransactionTrait,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use super::service::Service;
+use crate::repo::connected_repo::connected_methods;
+
+/// 持有连接的 service，方法与 `Service` 一一对应但不再需要传入 `db`
+///
+/// 连接可以是 `DatabaseConnection`（内部为连接池，clone 代价很低）或 `DbRouter`，
+/// 绑定 `DbRouter` 时读方法走副本、写方法走主库；
+/// 需要事务时通过 `service()` / `db()` 使用显式传连接的接口：
+///
+/// ```ignore
+/// let users = GenericService::new(UserRepo::new()).with_connection(db.clone());
+/// let user = users.find_by_id(1).await?;
+///
+/// users
+///     .service()
+///     .transaction(users.db(), |txn, svc| Box::pin(async move { svc.create(txn, model).await }))
+///     .await?;
+/// ```
+pub struct ConnectedService<E, Pk, S, Db = DatabaseConnection>
+where
+    E: EntityTrait + Send + Sync,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + 'static,
+    S: Service<E, Pk>,
+{
+    service: S,
+    db: Db,
+    _entity: std::marker::PhantomData<E>,
+    _pk: std::marker::PhantomData<Pk>,
+}
+
+impl<E, Pk, S, Db> ConnectedService<E, Pk, S, Db>
+where
+    E: EntityTrait + Send + Sync,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + 'static,
+    S: Service<E, Pk>,
+{
+    pub fn new(service: S, db: Db) -> Self {
+        Self {
+            service,
+            db,
+            _entity: std::marker::PhantomData,
+            _pk: std::marker::PhantomData,
+        }
+    }
+
+    pub fn service(&self) -> &S {
+        &self.service
+    }
+
+    pub fn db(&self) -> &Db {
+        &self.db
+    }
+}
+
+impl<E, Pk, S, Db> ConnectedService<E, Pk, S, Db>
+where
+    E: EntityTrait + Send + Sync,
+    E::ActiveModel: Send,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + 'static,
+    S: Service<E, Pk>,
+    Db: ReadConnection,
+{
+    // 读方法走 `ReadConnection::reader`，传入 `DbRouter` 时读副本
+    fn reader(&self) -> &Db::Reader {
+        self.db.reader()
+    }
+
+    fn writer(&self) -> &Db {
+        &self.db
+    }
+
+    connected_methods!(service);
+}
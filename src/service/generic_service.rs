@@ -1,16 +1,15 @@
-use crate::{dto::request::PageQueryParam, repo::repo::Repo};
-use async_trait::async_trait;
-use sea_orm::prelude::*;
-use sea_orm::{
-    sea_query::IntoCondition, DatabaseConnection, DbErr, DeleteResult, EntityTrait, PrimaryKeyTrait,
-};
+use crate::repo::repo::Repo;
+use sea_orm::{DatabaseTransaction, DbErr, EntityTrait, PrimaryKeyTrait, TransactionTrait};
+use std::future::Future;
+use std::pin::Pin;
 
+use super::connected_service::ConnectedService;
 use super::service::Service;
 
 pub struct GenericService<E, Pk, D>
 where
     E: EntityTrait,
-    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
     D: Repo<E, Pk>,
 {
     dao: D,
@@ -21,7 +20,7 @@ where
 impl<E, Pk, D> GenericService<E, Pk, D>
 where
     E: EntityTrait,
-    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
     D: Repo<E, Pk>,
 {
     pub fn new(dao: D) -> Self {
@@ -31,102 +30,63 @@ where
             _pk: std::marker::PhantomData,
         }
     }
-}
-
-#[async_trait]
-impl<E, Pk, D> Service<E, Pk> for GenericService<E, Pk, D>
-where
-    E: EntityTrait + Send + Sync,
-    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone,
-    D: Repo<E, Pk> + Send + Sync,
-{
-    async fn find_by_id(&self, db: &DatabaseConnection, id: Pk) -> Result<Option<E::Model>, DbErr> {
-        self.dao.find_by_id(db, id).await
-    }
-
-    async fn find_one_condition<F>(
-        &self,
-        db: &DatabaseConnection,
-        filter: F,
-    ) -> Result<Option<E::Model>, DbErr>
-    where
-        F: IntoCondition + Send,
-    {
-        self.dao.find_one_condition(db, filter).await
-    }
-
-    // 集合查询全量列表
-    async fn find_list(&self, db: &DatabaseConnection) -> Result<Vec<E::Model>, DbErr> {
-        self.dao.find_list(db).await
-    }
-
-    // 集合条件查询列表
-    async fn find_by_list_condition<F>(
-        &self,
-        db: &DatabaseConnection,
-        filter: F,
-    ) -> Result<Vec<E::Model>, DbErr>
-    where
-        F: IntoCondition + Send,
-    {
-        self.dao.find_by_list_condition(db, filter).await
-    }
-
-    async fn find_page(
-        &self,
-        db: &DatabaseConnection,
-        param: &PageQueryParam,
-    ) -> Result<(Vec<E::Model>, u64), DbErr> {
-        self.dao.find_page(db, param).await
-    }
 
-    // 分页条件查询
-    async fn find_page_condition<F>(
-        &self,
-        db: &DatabaseConnection,
-        filter: F,
-        param: &PageQueryParam,
-    ) -> Result<(Vec<E::Model>, u64), DbErr>
+    /// 绑定连接，得到不需要逐个方法传入 `db` 的 service
+    pub fn with_connection<Db>(self, db: Db) -> ConnectedService<E, Pk, Self, Db>
     where
-        F: IntoCondition + Send,
+        E: Send + Sync,
+        D: 'static,
     {
-        self.dao.find_page_condition(db, filter, param).await
-    }
-
-    async fn create(&self, db: &DatabaseConnection, model: E::Model) -> Result<E::Model, DbErr> {
-        self.dao.create(db, model).await
-    }
-
-    async fn update(&self, db: &DatabaseConnection, model: E::Model) -> Result<E::Model, DbErr> {
-        self.dao.update(db, model).await
+        ConnectedService::new(self, db)
     }
 
-    async fn update_by_condition<F>(
-        &self,
-        db: &DatabaseConnection,
-        filter: F,
-        column_updates: Vec<(E::Column, Value)>,
-    ) -> Result<u64, DbErr>
+    /// 在事务中执行回调，回调返回 Ok 时提交，返回 Err 时回滚
+    ///
+    /// 回调会拿到事务连接以及当前 service 本身，便于在同一事务中组合多个操作：
+    ///
+    /// ```ignore
+    /// service
+    ///     .transaction(&db, |txn, svc| {
+    ///         Box::pin(async move {
+    ///             let user = svc.create(txn, model).await?;
+    ///             svc.update_by_condition(txn, filter, updates).await?;
+    ///             Ok(user)
+    ///         })
+    ///     })
+    ///     .await
+    /// ```
+    pub async fn transaction<T, Er, C, F>(&self, db: &C, callback: F) -> Result<T, Er>
     where
-        F: IntoCondition + Send,
+        C: TransactionTrait,
+        F: for<'c> FnOnce(
+            &'c DatabaseTransaction,
+            &'c Self,
+        ) -> Pin<Box<dyn Future<Output = Result<T, Er>> + Send + 'c>>,
+        Er: From<DbErr>,
     {
-        self.dao
-            .update_by_condition(db, filter, column_updates)
-            .await
+        let txn = db.begin().await?;
+        match callback(&txn, self).await {
+            Ok(value) => {
+                txn.commit().await?;
+                Ok(value)
+            }
+            Err(err) => {
+                txn.rollback().await?;
+                Err(err)
+            }
+        }
     }
+}
 
-    async fn delete(&self, db: &DatabaseConnection, id: Pk) -> Result<DeleteResult, DbErr> {
-        self.dao.delete(db, id).await
-    }
+impl<E, Pk, D> Service<E, Pk> for GenericService<E, Pk, D>
+where
+    E: EntityTrait + Send + Sync,
+    Pk: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType> + Send + Sync + Clone + 'static,
+    D: Repo<E, Pk> + 'static,
+{
+    type Repo = D;
 
-    async fn delete_batch<C>(
-        &self,
-        db: &DatabaseConnection,
-        condition: C,
-    ) -> Result<DeleteResult, DbErr>
-    where
-        C: IntoCondition + Send,
-    {
-        self.dao.delete_batch(db, condition).await
+    fn repo(&self) -> &D {
+        &self.dao
     }
 }
@@ -1,2 +1,4 @@
+pub mod connected_service;
 pub mod generic_service;
+#[allow(clippy::module_inception)]
 pub mod service;
@@ -0,0 +1,165 @@
+mod common;
+
+use common::{post, user};
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::aggregate::Agg;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use sea_orm::sea_query::IntoCondition;
+use sea_orm::{ColumnTrait, Condition, FromQueryResult};
+use serde_json::{json, Value as JsonValue};
+
+type UserRepo = GenericRepo<user::Entity, i64>;
+
+async fn seeded() -> (sea_orm::DatabaseConnection, UserRepo) {
+    let db = common::setup().await;
+    let repo = UserRepo::new();
+    for (id, name, status) in [(1, "ann", 1), (2, "ben", 2), (3, "cat", 1), (4, "dan", 3)] {
+        repo.create(&db, common::user(id, name, status))
+            .await
+            .unwrap();
+    }
+    (db, repo)
+}
+
+#[derive(Debug, PartialEq, FromQueryResult)]
+struct StatusCount {
+    status: i32,
+    total: i64,
+    max_id: i64,
+}
+
+#[tokio::test]
+async fn aggregate_groups_into_typed_rows() {
+    let (db, repo) = seeded().await;
+    let rows: Vec<StatusCount> = repo
+        .aggregate(
+            &db,
+            user::Column::Id.ne(2),
+            vec![user::Column::Status],
+            vec![Agg::count("total"), Agg::max(user::Column::Id, "max_id")],
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        rows,
+        [
+            StatusCount {
+                status: 1,
+                total: 2,
+                max_id: 3
+            },
+            StatusCount {
+                status: 3,
+                total: 1,
+                max_id: 4
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn aggregate_returns_json_and_applies_having() {
+    let (db, repo) = seeded().await;
+    let total = Agg::count("total");
+    let rows: Vec<JsonValue> = repo
+        .aggregate_json(
+            &db,
+            Condition::all(),
+            vec![user::Column::Status],
+            vec![total.clone(), Agg::sum(user::Column::Id, "id_sum")],
+            Some(total.expr().gt(1).into_condition()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(rows, [json!({"status": 1, "total": 2, "id_sum": 4})]);
+
+    let overall: Vec<JsonValue> = repo
+        .aggregate_json(
+            &db,
+            Condition::all(),
+            vec![],
+            vec![Agg::count_distinct(user::Column::Status, "statuses")],
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(overall, [json!({"statuses": 3})]);
+}
+
+#[tokio::test]
+async fn aggregate_validates_measures() {
+    let (db, repo) = seeded().await;
+    let invalid = |measures: Vec<Agg<user::Column>>| {
+        let repo = &repo;
+        let db = &db;
+        async move {
+            let result = repo
+                .aggregate_json(
+                    db,
+                    Condition::all(),
+                    vec![user::Column::Status],
+                    measures,
+                    None,
+                )
+                .await;
+            matches!(result, Err(FrameworkError::Validation { errors }) if errors[0].field == "measures")
+        }
+    };
+    assert!(invalid(vec![]).await);
+    assert!(invalid(vec![Agg::sum(user::Column::Name, "names")]).await);
+    assert!(invalid(vec![Agg::count("status")]).await);
+    assert!(invalid(vec![Agg::count("n"), Agg::max(user::Column::Id, "n")]).await);
+}
+
+#[tokio::test]
+async fn aggregate_skips_soft_deleted_rows() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<post::Entity, i64>::new().with_soft_delete();
+    for id in 1..=3 {
+        repo.create(&db, common::post(id, "hello")).await.unwrap();
+    }
+    repo.delete(&db, 3).await.unwrap();
+
+    let rows: Vec<JsonValue> = repo
+        .aggregate_json(
+            &db,
+            Condition::all(),
+            vec![post::Column::Title],
+            vec![Agg::count("total")],
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(rows, [json!({"title": "hello", "total": 2})]);
+}
+
+#[tokio::test]
+async fn aggregate_json_returns_min_max_of_int_and_text_columns() {
+    let (db, repo) = seeded().await;
+    let rows: Vec<JsonValue> = repo
+        .aggregate_json(
+            &db,
+            Condition::all(),
+            vec![],
+            vec![
+                Agg::min(user::Column::Status, "min_status"),
+                Agg::max(user::Column::Status, "max_status"),
+                Agg::min(user::Column::Name, "first_name"),
+                Agg::max(user::Column::Name, "last_name"),
+            ],
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        rows,
+        [json!({
+            "min_status": 1,
+            "max_status": 3,
+            "first_name": "ann",
+            "last_name": "dan"
+        })]
+    );
+}
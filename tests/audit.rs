@@ -0,0 +1,109 @@
+mod common;
+
+use common::note;
+use rust_framework::context::RequestContext;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use sea_orm::{ColumnTrait, Value};
+
+fn repo() -> GenericRepo<note::Entity, i64> {
+    GenericRepo::new().with_audit()
+}
+
+#[tokio::test]
+async fn create_stamps_audit_columns() {
+    let db = common::setup().await;
+    let ctx = RequestContext::new().with_user(7i64);
+
+    let created = repo()
+        .create_with_context(&db, &ctx, common::note(1, "hello"))
+        .await
+        .unwrap();
+    assert!(created.created_at.is_some());
+    assert_eq!(created.created_at, created.updated_at);
+    assert_eq!(created.created_by, Some(7));
+    assert_eq!(created.updated_by, Some(7));
+
+    let anonymous = repo().create(&db, common::note(2, "world")).await.unwrap();
+    assert!(anonymous.created_at.is_some());
+    assert_eq!(anonymous.created_by, None);
+}
+
+#[tokio::test]
+async fn update_keeps_created_columns() {
+    let db = common::setup().await;
+    let repo = repo();
+    let created = repo
+        .create_with_context(
+            &db,
+            &RequestContext::new().with_user(7i64),
+            common::note(1, "hello"),
+        )
+        .await
+        .unwrap();
+
+    let mut edited = created.clone();
+    edited.body = "edited".to_string();
+    edited.created_by = Some(99);
+    edited.created_at = None;
+    let updated = repo
+        .update_with_context(&db, &RequestContext::new().with_user(8i64), edited)
+        .await
+        .unwrap();
+    assert_eq!(updated.body, "edited");
+    assert_eq!(updated.created_by, Some(7));
+    assert_eq!(updated.created_at, created.created_at);
+    assert_eq!(updated.updated_by, Some(8));
+    assert!(updated.updated_at >= created.updated_at);
+}
+
+#[tokio::test]
+async fn update_by_condition_stamps_updated_by() {
+    let db = common::setup().await;
+    let repo = repo();
+    repo.create(&db, common::note(1, "hello")).await.unwrap();
+
+    let affected = repo
+        .update_by_condition_with_context(
+            &db,
+            &RequestContext::new().with_user(9i64),
+            note::Column::Id.eq(1),
+            vec![(note::Column::Body, Value::from("bulk"))],
+        )
+        .await
+        .unwrap();
+    assert_eq!(affected, 1);
+    let saved = repo.find_by_id(&db, 1).await.unwrap().unwrap();
+    assert_eq!(saved.body, "bulk");
+    assert_eq!(saved.updated_by, Some(9));
+}
+
+#[tokio::test]
+async fn anonymous_writes_clear_client_supplied_operators() {
+    let db = common::setup().await;
+    let repo = repo();
+    let mut forged = common::note(1, "hello");
+    forged.created_by = Some(42);
+    forged.updated_by = Some(42);
+    let created = repo.create(&db, forged).await.unwrap();
+    assert_eq!(created.created_by, None);
+    assert_eq!(created.updated_by, None);
+
+    let ctx = RequestContext::new().with_user(7i64);
+    let mut edited = repo.update_with_context(&db, &ctx, created).await.unwrap();
+    assert_eq!(edited.updated_by, Some(7));
+
+    edited.updated_by = Some(42);
+    let updated = repo.update(&db, edited).await.unwrap();
+    assert_eq!(updated.updated_by, None);
+
+    repo.update_by_condition(
+        &db,
+        note::Column::Id.eq(1),
+        vec![(note::Column::UpdatedBy, Value::from(42i64))],
+    )
+    .await
+    .unwrap();
+    let saved = repo.find_by_id(&db, 1).await.unwrap().unwrap();
+    assert_eq!(saved.updated_by, None);
+}
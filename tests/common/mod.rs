@@ -0,0 +1,287 @@
+#![allow(dead_code)]
+
+use sea_orm::{ConnectionTrait, Database, DatabaseConnection, Schema};
+
+pub mod user {
+    use rust_framework::repo::filter::Filterable;
+    use rust_framework::repo::projection::Projectable;
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+    #[sea_orm(table_name = "users")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i64,
+        pub name: String,
+        #[sea_orm(unique)]
+        pub email: String,
+        pub status: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+
+    impl Filterable for Entity {
+        fn filterable_columns() -> Vec<Column> {
+            vec![Column::Id, Column::Name, Column::Status]
+        }
+    }
+
+    impl Projectable for Entity {
+        fn projectable_columns() -> Vec<Column> {
+            vec![Column::Id, Column::Name, Column::Status]
+        }
+    }
+}
+
+pub mod post {
+    use rust_framework::repo::soft_delete::{SoftDelete, SoftDeleteColumn};
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+    #[sea_orm(table_name = "posts")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i64,
+        pub title: String,
+        pub deleted_at: Option<DateTime>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+
+    impl SoftDelete for Entity {
+        fn soft_delete_column() -> SoftDeleteColumn<Column> {
+            SoftDeleteColumn::DeletedAt(Column::DeletedAt)
+        }
+    }
+}
+
+pub mod note {
+    use rust_framework::repo::audit::{AuditColumns, Auditable};
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+    #[sea_orm(table_name = "notes")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i64,
+        pub body: String,
+        pub created_at: Option<DateTimeUtc>,
+        pub updated_at: Option<DateTimeUtc>,
+        pub created_by: Option<i64>,
+        pub updated_by: Option<i64>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+
+    impl Auditable for Entity {
+        fn audit_columns() -> AuditColumns<Column> {
+            AuditColumns {
+                created_at: Some(Column::CreatedAt),
+                updated_at: Some(Column::UpdatedAt),
+                created_by: Some(Column::CreatedBy),
+                updated_by: Some(Column::UpdatedBy),
+            }
+        }
+    }
+}
+
+pub mod document {
+    use rust_framework::repo::version::Versioned;
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+    #[sea_orm(table_name = "documents")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i64,
+        pub content: String,
+        pub version: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+
+    impl Versioned for Entity {
+        fn version_column() -> Column {
+            Column::Version
+        }
+    }
+}
+
+pub mod membership {
+    use sea_orm::entity::prelude::*;
+
+    /// 两列主键的关联表
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+    #[sea_orm(table_name = "memberships")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub user_id: i64,
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub group_id: i64,
+        pub role: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+pub mod team {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+    #[sea_orm(table_name = "teams")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i64,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(has_many = "super::player::Entity")]
+        Player,
+    }
+
+    impl Related<super::player::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Player.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+pub mod player {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+    #[sea_orm(table_name = "players")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i64,
+        pub team_id: Option<i64>,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(
+            belongs_to = "super::team::Entity",
+            from = "Column::TeamId",
+            to = "super::team::Column::Id"
+        )]
+        Team,
+    }
+
+    impl Related<super::team::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Team.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+pub mod profile {
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    // serde 字段名与列名不一致
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+    #[sea_orm(table_name = "profiles")]
+    #[serde(rename_all = "camelCase")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i64,
+        pub display_name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+/// 创建内存 SQLite 连接并建表，启用外键约束
+pub async fn setup() -> DatabaseConnection {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    db.execute_unprepared("PRAGMA foreign_keys = ON")
+        .await
+        .unwrap();
+    let backend = db.get_database_backend();
+    let schema = Schema::new(backend);
+    let tables = [
+        schema.create_table_from_entity(user::Entity),
+        schema.create_table_from_entity(post::Entity),
+        schema.create_table_from_entity(note::Entity),
+        schema.create_table_from_entity(document::Entity),
+        schema.create_table_from_entity(membership::Entity),
+        schema.create_table_from_entity(team::Entity),
+        schema.create_table_from_entity(player::Entity),
+        schema.create_table_from_entity(profile::Entity),
+    ];
+    for table in tables {
+        db.execute(backend.build(&table)).await.unwrap();
+    }
+    db
+}
+
+pub fn user(id: i64, name: &str, status: i32) -> user::Model {
+    user::Model {
+        id,
+        name: name.to_string(),
+        email: format!("{}@example.com", name),
+        status,
+    }
+}
+
+pub fn post(id: i64, title: &str) -> post::Model {
+    post::Model {
+        id,
+        title: title.to_string(),
+        deleted_at: None,
+    }
+}
+
+pub fn note(id: i64, body: &str) -> note::Model {
+    note::Model {
+        id,
+        body: body.to_string(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        updated_by: None,
+    }
+}
+
+pub fn document(id: i64, content: &str) -> document::Model {
+    document::Model {
+        id,
+        content: content.to_string(),
+        version: 0,
+    }
+}
+
+pub fn membership(user_id: i64, group_id: i64, role: &str) -> membership::Model {
+    membership::Model {
+        user_id,
+        group_id,
+        role: role.to_string(),
+    }
+}
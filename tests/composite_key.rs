@@ -0,0 +1,116 @@
+mod common;
+
+use common::membership;
+use rust_framework::dto::request::{CursorQueryParam, IdsReq};
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use rust_framework::repo::upsert::UpsertConflict;
+use sea_orm::{ActiveValue, Condition};
+
+type MembershipRepo = GenericRepo<membership::Entity, (i64, i64)>;
+
+async fn seed(db: &sea_orm::DatabaseConnection, repo: &MembershipRepo) {
+    for (user_id, group_id) in [(1, 10), (1, 20), (2, 10)] {
+        repo.create(db, common::membership(user_id, group_id, "member"))
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn find_and_delete_by_tuple_key() {
+    let db = common::setup().await;
+    let repo = MembershipRepo::new();
+    seed(&db, &repo).await;
+
+    let found = repo.find_by_id(&db, (1, 20)).await.unwrap().unwrap();
+    assert_eq!((found.user_id, found.group_id), (1, 20));
+    assert!(repo.find_by_id(&db, (2, 20)).await.unwrap().is_none());
+
+    let result = repo.delete(&db, (1, 10)).await.unwrap();
+    assert_eq!(result.rows_affected, 1);
+    assert!(repo.find_by_id(&db, (1, 10)).await.unwrap().is_none());
+    assert!(repo.find_by_id(&db, (2, 10)).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn find_and_delete_by_tuple_ids() {
+    let db = common::setup().await;
+    let repo = MembershipRepo::new();
+    seed(&db, &repo).await;
+    let ids: Vec<(i64, i64)> = IdsReq {
+        ids: "1:20, 2:10, 2:20".to_string(),
+    }
+    .parse_pairs()
+    .unwrap();
+
+    assert_eq!(repo.find_by_ids(&db, ids.clone()).await.unwrap().len(), 2);
+    assert_eq!(repo.delete_by_ids(&db, ids).await.unwrap().rows_affected, 2);
+    assert_eq!(repo.find_list(&db).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn update_and_patch_by_tuple_key() {
+    let db = common::setup().await;
+    let repo = MembershipRepo::new();
+    seed(&db, &repo).await;
+
+    let mut model = common::membership(1, 20, "owner");
+    assert_eq!(repo.update(&db, model.clone()).await.unwrap(), model);
+
+    let patched = repo
+        .patch_active(
+            &db,
+            membership::ActiveModel {
+                user_id: ActiveValue::Unchanged(1),
+                group_id: ActiveValue::Unchanged(20),
+                role: ActiveValue::Set("admin".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    model.role = "admin".to_string();
+    assert_eq!(patched, model);
+    let untouched = repo.find_by_id(&db, (1, 10)).await.unwrap().unwrap();
+    assert_eq!(untouched.role, "member");
+}
+
+#[tokio::test]
+async fn upsert_and_cursor_page_by_tuple_key() {
+    let db = common::setup().await;
+    let repo = MembershipRepo::new();
+    seed(&db, &repo).await;
+
+    let conflict = UpsertConflict::primary_key::<membership::Entity>();
+    let upserted = repo
+        .upsert(&db, common::membership(2, 10, "owner"), &conflict)
+        .await
+        .unwrap();
+    assert_eq!(upserted.role, "owner");
+
+    let mut param = CursorQueryParam {
+        cursor: None,
+        page_size: 2,
+        sort: None,
+    };
+    let first = repo.find_cursor_page(&db, &param).await.unwrap();
+    param.cursor = first.next_cursor.clone();
+    let second = repo.find_cursor_page(&db, &param).await.unwrap();
+    let keys: Vec<(i64, i64)> = first
+        .data
+        .iter()
+        .chain(&second.data)
+        .map(|m| (m.user_id, m.group_id))
+        .collect();
+    assert_eq!(keys, vec![(1, 10), (1, 20), (2, 10)]);
+    assert!(second.next_cursor.is_none());
+
+    repo.purge(&db, (1, 10)).await.unwrap();
+    assert_eq!(
+        repo.find_with_deleted(&db, Condition::all())
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use common::{post, user};
+use rust_framework::db::DbRouter;
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use rust_framework::service::generic_service::GenericService;
+use rust_framework::service::service::Service;
+use sea_orm::ColumnTrait;
+use std::sync::Arc;
+
+type UserService = GenericService<user::Entity, i64, GenericRepo<user::Entity, i64>>;
+
+#[tokio::test]
+async fn connected_service_runs_without_db_argument() {
+    let users = UserService::new(GenericRepo::new()).with_connection(common::setup().await);
+
+    users.create(common::user(1, "alice", 0)).await.unwrap();
+    users.create(common::user(2, "bob", 1)).await.unwrap();
+
+    assert_eq!(users.find_by_id(1).await.unwrap().unwrap().name, "alice");
+    assert_eq!(
+        users
+            .count_condition(user::Column::Status.eq(1))
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(users.delete(2).await.unwrap().rows_affected, 1);
+    assert_eq!(users.find_list().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn connected_service_keeps_explicit_api_for_transactions() {
+    let users = UserService::new(GenericRepo::new()).with_connection(common::setup().await);
+
+    let result: Result<(), FrameworkError> = users
+        .service()
+        .transaction(users.db(), |txn, svc| {
+            Box::pin(async move {
+                svc.create(txn, common::user(1, "alice", 0)).await?;
+                Err(FrameworkError::forbidden("abort"))
+            })
+        })
+        .await;
+
+    assert!(result.is_err());
+    assert!(!users.exists_by_id(1).await.unwrap());
+}
+
+#[tokio::test]
+async fn connected_service_can_own_a_router() {
+    let router = DbRouter::new(common::setup().await).with_reader(common::setup().await);
+    let users = Arc::new(UserService::new(GenericRepo::new()).with_connection(router));
+
+    // 在 handler 中通常以 Arc 共享并跨任务使用
+    let handle = {
+        let users = users.clone();
+        tokio::spawn(async move { users.create(common::user(1, "alice", 0)).await })
+    };
+    handle.await.unwrap().unwrap();
+
+    assert!(users.find_by_id(1).await.unwrap().is_none());
+    assert!(users
+        .service()
+        .find_by_id(&users.db().read_your_writes(), 1)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn connected_repo_runs_without_db_argument() {
+    let posts = GenericRepo::<post::Entity, i64>::new()
+        .with_soft_delete()
+        .with_connection(common::setup().await);
+
+    posts.create(common::post(1, "kept")).await.unwrap();
+    posts.create(common::post(2, "gone")).await.unwrap();
+    posts.delete(2).await.unwrap();
+
+    assert_eq!(posts.count().await.unwrap(), 1);
+    assert_eq!(
+        posts
+            .repo()
+            .find_with_deleted(posts.db(), sea_orm::Condition::all())
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::{post, user};
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use sea_orm::ColumnTrait;
+
+#[tokio::test]
+async fn count_and_exists_by_condition() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    for (id, status) in [(1, 0), (2, 1), (3, 1)] {
+        repo.create(&db, common::user(id, &format!("user{id}"), status))
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(repo.count(&db).await.unwrap(), 3);
+    assert_eq!(
+        repo.count_condition(&db, user::Column::Status.eq(1))
+            .await
+            .unwrap(),
+        2
+    );
+    assert!(repo.exists_by_id(&db, 2).await.unwrap());
+    assert!(!repo.exists_by_id(&db, 9).await.unwrap());
+    assert!(repo
+        .exists_condition(&db, user::Column::Email.eq("user3@example.com"))
+        .await
+        .unwrap());
+    assert!(!repo
+        .exists_condition(&db, user::Column::Status.eq(5))
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn count_and_exists_skip_soft_deleted_rows() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<post::Entity, i64>::new().with_soft_delete();
+    repo.create(&db, common::post(1, "kept")).await.unwrap();
+    repo.create(&db, common::post(2, "gone")).await.unwrap();
+    repo.delete(&db, 2).await.unwrap();
+
+    assert_eq!(repo.count(&db).await.unwrap(), 1);
+    assert!(!repo.exists_by_id(&db, 2).await.unwrap());
+}
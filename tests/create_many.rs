@@ -0,0 +1,39 @@
+mod common;
+
+use common::{note, user};
+use rust_framework::context::RequestContext;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+
+#[tokio::test]
+async fn create_many_inserts_across_chunks() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new().with_insert_chunk_size(3);
+    let users = (1..=10)
+        .map(|id| common::user(id, &format!("user{id}"), 0))
+        .collect();
+
+    assert_eq!(repo.create_many(&db, users).await.unwrap(), 10);
+    assert_eq!(repo.find_list(&db).await.unwrap().len(), 10);
+    assert_eq!(repo.create_many(&db, vec![]).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn create_many_returning_stamps_audit_columns() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<note::Entity, i64>::new()
+        .with_audit()
+        .with_insert_chunk_size(2);
+    let ctx = RequestContext::new().with_user(7i64);
+    let notes = (1..=5).map(|id| common::note(id, "hello")).collect();
+
+    let inserted = repo
+        .create_many_returning_with_context(&db, &ctx, notes)
+        .await
+        .unwrap();
+
+    assert_eq!(inserted.len(), 5);
+    assert!(inserted
+        .iter()
+        .all(|n| n.created_by == Some(7) && n.created_at.is_some()));
+}
@@ -0,0 +1,18 @@
+mod common;
+
+use common::user;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+
+#[tokio::test]
+async fn update_persists_every_column() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    let mut model = repo.create(&db, common::user(1, "alice", 0)).await.unwrap();
+
+    model.name = "alicia".to_string();
+    model.status = 2;
+    repo.update(&db, model.clone()).await.unwrap();
+
+    assert_eq!(repo.find_by_id(&db, 1).await.unwrap(), Some(model));
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::{player, team, user};
+use rust_framework::dto::request::CursorQueryParam;
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use sea_orm::DatabaseConnection;
+
+async fn seed(db: &DatabaseConnection) -> GenericRepo<user::Entity, i64> {
+    let repo = GenericRepo::new();
+    for (id, status) in [(1, 0), (2, 1), (3, 0), (4, 1), (5, 1)] {
+        let name = format!("user{}", id);
+        repo.create(db, common::user(id, &name, status))
+            .await
+            .unwrap();
+    }
+    repo
+}
+
+fn param(cursor: Option<String>, sort: &str) -> CursorQueryParam {
+    CursorQueryParam {
+        cursor,
+        page_size: 2,
+        sort: Some(sort.to_string()),
+    }
+}
+
+#[tokio::test]
+async fn cursor_pages_forward_and_backward() {
+    let db = common::setup().await;
+    let repo = seed(&db).await;
+
+    let first = repo
+        .find_cursor_page(&db, &param(None, "-status"))
+        .await
+        .unwrap();
+    assert_eq!(first.data.iter().map(|m| m.id).collect::<Vec<_>>(), [5, 4]);
+    assert!(first.prev_cursor.is_none());
+
+    let second = repo
+        .find_cursor_page(&db, &param(first.next_cursor.clone(), "-status"))
+        .await
+        .unwrap();
+    assert_eq!(second.data.iter().map(|m| m.id).collect::<Vec<_>>(), [2, 3]);
+
+    let third = repo
+        .find_cursor_page(&db, &param(second.next_cursor.clone(), "-status"))
+        .await
+        .unwrap();
+    assert_eq!(third.data.iter().map(|m| m.id).collect::<Vec<_>>(), [1]);
+    assert!(third.next_cursor.is_none());
+
+    let back = repo
+        .find_cursor_page(&db, &param(second.prev_cursor.clone(), "-status"))
+        .await
+        .unwrap();
+    assert_eq!(back.data.iter().map(|m| m.id).collect::<Vec<_>>(), [5, 4]);
+    assert!(back.prev_cursor.is_none());
+    assert!(back.next_cursor.is_some());
+}
+
+#[tokio::test]
+async fn cursor_rejects_tampered_or_mismatched_tokens() {
+    let db = common::setup().await;
+    let repo = seed(&db).await;
+
+    let invalid = param(Some("not-a-cursor".to_string()), "id");
+    assert!(repo.find_cursor_page(&db, &invalid).await.is_err());
+
+    let first = repo
+        .find_cursor_page(&db, &param(None, "status"))
+        .await
+        .unwrap();
+    let other_sort = param(first.next_cursor, "name");
+    assert!(repo.find_cursor_page(&db, &other_sort).await.is_err());
+
+    let mixed = param(None, "status,-name");
+    assert!(repo.find_cursor_page(&db, &mixed).await.is_err());
+}
+
+#[tokio::test]
+async fn cursor_rejects_nullable_sort_fields() {
+    let db = common::setup().await;
+    let teams = GenericRepo::<team::Entity, i64>::new();
+    let team = team::Model {
+        id: 1,
+        name: "red".to_string(),
+    };
+    teams.create(&db, team).await.unwrap();
+    let repo = GenericRepo::<player::Entity, i64>::new();
+    for (id, team_id) in [(1, Some(1)), (2, None)] {
+        let player = player::Model {
+            id,
+            team_id,
+            name: format!("player{}", id),
+        };
+        repo.create(&db, player).await.unwrap();
+    }
+
+    let result = repo.find_cursor_page(&db, &param(None, "team_id")).await;
+    assert!(matches!(
+        result,
+        Err(FrameworkError::Validation { errors })
+            if errors[0].field == "sort" && errors[0].message.contains("nullable")
+    ));
+}
+
+#[tokio::test]
+async fn cursor_rejects_invalid_page_size() {
+    let db = common::setup().await;
+    let repo = seed(&db).await;
+    for page_size in [0, u64::MAX] {
+        let param = CursorQueryParam {
+            page_size,
+            ..param(None, "id")
+        };
+        let result = repo.find_cursor_page(&db, &param).await;
+        assert!(matches!(
+            result,
+            Err(FrameworkError::Validation { errors }) if errors[0].field == "page_size"
+        ));
+    }
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use async_trait::async_trait;
+use common::user;
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use rust_framework::service::generic_service::GenericService;
+use sea_orm::ConnectionTrait;
+
+// 只覆盖 create，其余方法走默认委托
+struct UserDao {
+    generic_dao: GenericRepo<user::Entity, i64>,
+}
+
+#[async_trait]
+impl Repo<user::Entity, i64> for UserDao {
+    type Delegate = GenericRepo<user::Entity, i64>;
+
+    fn delegate(&self) -> &Self::Delegate {
+        &self.generic_dao
+    }
+
+    async fn create<C>(&self, db: &C, mut model: user::Model) -> Result<user::Model, FrameworkError>
+    where
+        C: ConnectionTrait,
+    {
+        model.name = model.name.to_uppercase();
+        self.generic_dao.create(db, model).await
+    }
+}
+
+#[tokio::test]
+async fn custom_repo_overrides_one_method_and_delegates_the_rest() {
+    let db = common::setup().await;
+    let dao = UserDao {
+        generic_dao: GenericRepo::new(),
+    };
+
+    dao.create(&db, common::user(1, "alice", 0)).await.unwrap();
+
+    assert_eq!(dao.find_by_id(&db, 1).await.unwrap().unwrap().name, "ALICE");
+    assert_eq!(dao.count(&db).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn connected_service_uses_custom_repo_overrides() {
+    let users = GenericService::new(UserDao {
+        generic_dao: GenericRepo::new(),
+    })
+    .with_connection(common::setup().await);
+
+    users.create(common::user(1, "bob", 0)).await.unwrap();
+
+    assert_eq!(users.find_by_id(1).await.unwrap().unwrap().name, "BOB");
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::{player, user};
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+
+#[tokio::test]
+async fn unique_violation_maps_to_conflict() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    repo.create(&db, common::user(1, "alice", 0)).await.unwrap();
+
+    let mut duplicate = common::user(2, "bob", 0);
+    duplicate.email = "alice@example.com".to_string();
+    let err = repo.create(&db, duplicate).await.unwrap_err();
+    assert!(matches!(err, FrameworkError::Conflict(_)), "{:?}", err);
+}
+
+#[tokio::test]
+async fn updating_missing_row_maps_to_not_found() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+
+    let err = repo
+        .update(&db, common::user(42, "ghost", 0))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, FrameworkError::NotFound(_)), "{:?}", err);
+}
+
+#[tokio::test]
+async fn unknown_sort_field_maps_to_validation() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    let param = rust_framework::dto::request::PageQueryParam {
+        page_num: 0,
+        page_size: 10,
+        sort: Some("password".to_string()),
+        ..Default::default()
+    };
+
+    match repo.find_page(&db, &param).await {
+        Err(FrameworkError::Validation { errors }) => assert_eq!(errors[0].field, "sort"),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn missing_reference_maps_to_foreign_key_violation() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<player::Entity, i64>::new();
+    let orphan = player::Model {
+        id: 1,
+        team_id: Some(99),
+        name: "ann".to_string(),
+    };
+
+    let err = repo.create(&db, orphan).await.unwrap_err();
+    assert!(
+        matches!(err, FrameworkError::ForeignKeyViolation(_)),
+        "{:?}",
+        err
+    );
+}
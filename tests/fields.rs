@@ -0,0 +1,93 @@
+mod common;
+
+use common::user;
+use rust_framework::dto::request::{ListQueryParam, PageQueryParam};
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use sea_orm::ColumnTrait;
+use serde_json::json;
+
+async fn seeded() -> (sea_orm::DatabaseConnection, GenericRepo<user::Entity, i64>) {
+    let db = common::setup().await;
+    let repo = GenericRepo::new().with_projection();
+    for (id, status) in [(1, 1), (2, 0), (3, 1)] {
+        repo.create(&db, common::user(id, &format!("user{id}"), status))
+            .await
+            .unwrap();
+    }
+    (db, repo)
+}
+
+#[tokio::test]
+async fn find_list_fields_selects_only_requested_columns() {
+    let (db, repo) = seeded().await;
+    let param = ListQueryParam {
+        sort: Some("-id".to_string()),
+        fields: Some("id, name".to_string()),
+    };
+
+    let rows = repo.find_list_fields(&db, &param).await.unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            json!({ "id": 3, "name": "user3" }),
+            json!({ "id": 2, "name": "user2" }),
+            json!({ "id": 1, "name": "user1" }),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn find_page_fields_paginates_projection() {
+    let (db, repo) = seeded().await;
+    let param = PageQueryParam {
+        page_num: 0,
+        page_size: 1,
+        fields: Some("name".to_string()),
+        ..Default::default()
+    };
+
+    let page = repo
+        .find_page_fields_condition(&db, user::Column::Status.eq(1), &param)
+        .await
+        .unwrap();
+    assert_eq!(page.total, Some(2));
+    assert_eq!(page.data, vec![json!({ "name": "user1" })]);
+}
+
+#[tokio::test]
+async fn unknown_fields_are_rejected() {
+    let (db, repo) = seeded().await;
+    let param = ListQueryParam {
+        sort: None,
+        fields: Some("id,password,email".to_string()),
+    };
+
+    let err = repo.find_list_fields(&db, &param).await.unwrap_err();
+    match err {
+        FrameworkError::Validation { errors } => {
+            assert_eq!(errors.len(), 2);
+            assert_eq!(errors[0].field, "fields");
+            assert!(errors[0].message.contains("password"));
+            assert!(errors[1].message.contains("email"));
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn fields_require_projection_whitelist() {
+    let (db, _) = seeded().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    let param = ListQueryParam {
+        sort: None,
+        fields: Some("id".to_string()),
+    };
+
+    let err = repo.find_list_fields(&db, &param).await.unwrap_err();
+    assert!(matches!(err, FrameworkError::Validation { .. }), "{err:?}");
+
+    let all = ListQueryParam::default();
+    assert_eq!(repo.find_list_fields(&db, &all).await.unwrap().len(), 3);
+}
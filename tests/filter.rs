@@ -0,0 +1,166 @@
+mod common;
+
+use common::user;
+use rust_framework::dto::request::{FilterOp, FilterParam, PageQueryParam};
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+
+fn repo() -> GenericRepo<user::Entity, i64> {
+    GenericRepo::new().with_filters()
+}
+
+fn filters(pairs: &[(&str, &str)]) -> Vec<FilterParam> {
+    FilterParam::from_query_pairs(pairs.iter().map(|(k, v)| (*k, *v))).unwrap()
+}
+
+fn invalid_fields(err: FrameworkError) -> Vec<String> {
+    match err {
+        FrameworkError::Validation { errors } => errors.into_iter().map(|e| e.field).collect(),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn query_pairs_parse_into_filters() {
+    let parsed = filters(&[
+        ("filter[name][like]", "ali"),
+        ("filter[status]", "1"),
+        ("page_num", "0"),
+    ]);
+    assert_eq!(
+        parsed,
+        vec![
+            FilterParam {
+                field: "name".to_string(),
+                op: FilterOp::Like,
+                value: "ali".to_string(),
+            },
+            FilterParam {
+                field: "status".to_string(),
+                op: FilterOp::Eq,
+                value: "1".to_string(),
+            },
+        ]
+    );
+
+    let err = FilterParam::from_query_pairs([("filter[name][regex]", "a.*"), ("filter[]", "x")])
+        .unwrap_err();
+    assert_eq!(
+        invalid_fields(err),
+        vec!["filter[name][regex]".to_string(), "filter[]".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn filter_condition_drives_page_queries() {
+    let db = common::setup().await;
+    let repo = repo();
+    for (id, name, status) in [
+        (1, "alice", 1),
+        (2, "alina", 0),
+        (3, "bob", 2),
+        (4, "malik", 3),
+    ] {
+        repo.create(&db, common::user(id, name, status))
+            .await
+            .unwrap();
+    }
+    let param = PageQueryParam {
+        page_num: 0,
+        page_size: 10,
+        ..Default::default()
+    };
+    let ids = |filters: Vec<FilterParam>| {
+        let repo = &repo;
+        let db = &db;
+        let param = &param;
+        async move {
+            let condition = repo.filter_condition(&filters).unwrap();
+            let page = repo
+                .find_page_condition(db, condition, param)
+                .await
+                .unwrap();
+            page.data.iter().map(|u| u.id).collect::<Vec<_>>()
+        }
+    };
+
+    assert_eq!(
+        ids(filters(&[("filter[name][like]", "li")])).await,
+        [1, 2, 4]
+    );
+    assert_eq!(
+        ids(filters(&[
+            ("filter[name][like]", "li"),
+            ("filter[status][gte]", "1")
+        ]))
+        .await,
+        [1, 4]
+    );
+    assert_eq!(
+        ids(filters(&[("filter[status][in]", "0, 2")])).await,
+        [2, 3]
+    );
+    assert_eq!(
+        ids(filters(&[
+            ("filter[id][ne]", "1"),
+            ("filter[status][lt]", "3")
+        ]))
+        .await,
+        [2, 3]
+    );
+}
+
+#[test]
+fn filter_condition_validates_fields_and_values() {
+    let repo = repo();
+    let err = repo
+        .filter_condition(&filters(&[
+            ("filter[email]", "a@example.com"),
+            ("filter[status][gt]", "abc"),
+            ("filter[status][like]", "1"),
+            ("filter[name][null]", "maybe"),
+        ]))
+        .unwrap_err();
+    assert_eq!(
+        invalid_fields(err),
+        vec![
+            "filter[email]".to_string(),
+            "filter[status]".to_string(),
+            "filter[status]".to_string(),
+            "filter[name]".to_string(),
+        ]
+    );
+
+    // 未调用 with_filters 时不接受任何过滤
+    let plain = GenericRepo::<user::Entity, i64>::new();
+    assert!(plain
+        .filter_condition(&filters(&[("filter[name]", "alice")]))
+        .is_err());
+}
+
+#[tokio::test]
+async fn like_treats_wildcards_in_value_literally() {
+    let db = common::setup().await;
+    let repo = repo();
+    for (id, name) in [(1, "50%off"), (2, "500ff"), (3, "a_b"), (4, "axb")] {
+        repo.create(&db, common::user(id, name, 0)).await.unwrap();
+    }
+    let ids = |condition| {
+        let repo = &repo;
+        let db = &db;
+        async move {
+            let users = repo.find_by_list_condition(db, condition).await.unwrap();
+            users.iter().map(|u| u.id).collect::<Vec<_>>()
+        }
+    };
+
+    let condition = repo
+        .filter_condition(&filters(&[("filter[name][like]", "%")]))
+        .unwrap();
+    assert_eq!(ids(condition).await, [1]);
+    let condition = repo
+        .filter_condition(&filters(&[("filter[name][like]", "_")]))
+        .unwrap();
+    assert_eq!(ids(condition).await, [3]);
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use common::{post, user};
+use rust_framework::dto::request::IdsReq;
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+
+fn ids(ids: &str) -> IdsReq {
+    IdsReq {
+        ids: ids.to_string(),
+    }
+}
+
+#[test]
+fn ids_req_parses_trimmed_unique_ids() {
+    assert_eq!(ids(" 3, 1,3 ,2").parse::<i64>().unwrap(), vec![3, 1, 2]);
+}
+
+#[test]
+fn ids_req_rejects_bad_tokens_and_limits() {
+    let invalid = |req: IdsReq, limit: usize| {
+        matches!(
+            req.parse_with_limit::<i64>(limit),
+            Err(FrameworkError::Validation { errors }) if errors[0].field == "ids"
+        )
+    };
+    assert!(invalid(ids(""), 10));
+    assert!(invalid(ids("1,,2"), 10));
+    assert!(invalid(ids("1,abc"), 10));
+    assert!(invalid(ids("1,2,3"), 2));
+    let huge = (0..500_000).map(|i| i.to_string()).collect::<Vec<_>>();
+    assert!(invalid(ids(&huge.join(",")), IdsReq::MAX_IDS));
+}
+
+#[test]
+fn ids_req_limit_counts_unique_ids() {
+    assert_eq!(
+        ids("1,1,2,2,1").parse_with_limit::<i64>(2).unwrap(),
+        vec![1, 2]
+    );
+}
+
+#[tokio::test]
+async fn find_by_ids_returns_matching_rows() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    for id in 1..=4 {
+        repo.create(&db, common::user(id, &format!("user{id}"), 0))
+            .await
+            .unwrap();
+    }
+
+    let found = repo
+        .find_by_ids(&db, ids("1,3,9").parse().unwrap())
+        .await
+        .unwrap();
+    let mut found_ids: Vec<i64> = found.iter().map(|u| u.id).collect();
+    found_ids.sort();
+    assert_eq!(found_ids, vec![1, 3]);
+    assert!(repo.find_by_ids(&db, vec![]).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn delete_by_ids_respects_soft_delete() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<post::Entity, i64>::new().with_soft_delete();
+    for id in 1..=3 {
+        repo.create(&db, common::post(id, "hello")).await.unwrap();
+    }
+
+    let result = repo.delete_by_ids(&db, vec![1, 2]).await.unwrap();
+    assert_eq!(result.rows_affected, 2);
+    let remaining = repo.find_list(&db).await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, 3);
+    assert_eq!(
+        repo.find_with_deleted(&db, sea_orm::Condition::all())
+            .await
+            .unwrap()
+            .len(),
+        3
+    );
+}
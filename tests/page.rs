@@ -0,0 +1,71 @@
+mod common;
+
+use common::user;
+use rust_framework::dto::request::PageQueryParam;
+use rust_framework::dto::response::PageResponse;
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+
+fn invalid_field<T>(result: Result<T, FrameworkError>) -> Option<String> {
+    match result {
+        Err(FrameworkError::Validation { errors }) => Some(errors[0].field.clone()),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn find_page_rejects_zero_page_size() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    for skip_total in [false, true] {
+        let param = PageQueryParam {
+            page_size: 0,
+            skip_total,
+            ..Default::default()
+        };
+        let result = repo.find_page(&db, &param).await;
+        assert_eq!(invalid_field(result).as_deref(), Some("page_size"));
+    }
+}
+
+#[tokio::test]
+async fn find_page_rejects_out_of_range_pages() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    for skip_total in [false, true] {
+        let param = PageQueryParam {
+            page_num: u64::MAX,
+            page_size: 10,
+            skip_total,
+            ..Default::default()
+        };
+        let result = repo.find_page(&db, &param).await;
+        assert_eq!(invalid_field(result).as_deref(), Some("page_num"));
+    }
+
+    let param = PageQueryParam {
+        page_size: u64::MAX,
+        skip_total: true,
+        ..Default::default()
+    };
+    let result = repo.find_page(&db, &param).await;
+    assert_eq!(invalid_field(result).as_deref(), Some("page_size"));
+}
+
+#[test]
+fn page_response_does_not_overflow_on_last_page() {
+    let page = PageResponse::<()>::new(vec![], u64::MAX, 10, 100);
+    assert!(!page.has_next);
+}
+
+#[test]
+fn page_param_defaults_to_documented_page_size() {
+    assert_eq!(
+        PageQueryParam::default().page_size,
+        PageQueryParam::DEFAULT_PAGE_SIZE
+    );
+    let param: PageQueryParam = serde_json::from_str("{}").unwrap();
+    assert_eq!(param.page_num, 0);
+    assert_eq!(param.page_size, PageQueryParam::DEFAULT_PAGE_SIZE);
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use common::{profile, user};
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use sea_orm::{ActiveValue, ColumnTrait, DbErr, Value};
+use serde_json::json;
+
+#[tokio::test]
+async fn patch_only_writes_sent_fields() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    repo.create(&db, common::user(1, "alice", 0)).await.unwrap();
+
+    // 其他请求先修改了 status，局部更新不应覆盖它
+    repo.update_by_condition(
+        &db,
+        user::Column::Id.eq(1),
+        vec![(user::Column::Status, Value::from(3))],
+    )
+    .await
+    .unwrap();
+    let patched = repo
+        .patch(&db, 1, json!({ "name": "alicia" }))
+        .await
+        .unwrap();
+
+    assert_eq!(patched.name, "alicia");
+    assert_eq!(patched.status, 3);
+    assert_eq!(patched.email, "alice@example.com");
+}
+
+#[tokio::test]
+async fn patch_rejects_unknown_fields() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    repo.create(&db, common::user(1, "alice", 0)).await.unwrap();
+
+    let err = repo
+        .patch(&db, 1, json!({ "name": "alicia", "nickname": "al" }))
+        .await
+        .unwrap_err();
+    match err {
+        FrameworkError::Validation { errors } => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].field, "nickname");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+    let unchanged = repo.find_by_id(&db, 1).await.unwrap().unwrap();
+    assert_eq!(unchanged.name, "alice");
+}
+
+#[tokio::test]
+async fn patch_rejects_models_with_renamed_serde_fields() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<profile::Entity, i64>::new();
+    let profile = profile::Model {
+        id: 1,
+        display_name: "alice".to_string(),
+    };
+    repo.create(&db, profile).await.unwrap();
+
+    for body in [
+        json!({ "displayName": "alicia" }),
+        json!({ "display_name": "alicia" }),
+    ] {
+        let err = repo.patch(&db, 1, body).await.unwrap_err();
+        assert!(
+            matches!(err, FrameworkError::Database(DbErr::Custom(_))),
+            "{err:?}"
+        );
+    }
+    let unchanged = repo.find_by_id(&db, 1).await.unwrap().unwrap();
+    assert_eq!(unchanged.display_name, "alice");
+}
+
+#[tokio::test]
+async fn patch_missing_row_is_not_found() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+
+    let err = repo
+        .patch(&db, 42, json!({ "name": "ghost" }))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, FrameworkError::NotFound(_)));
+}
+
+#[tokio::test]
+async fn patch_active_only_writes_set_columns() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    repo.create(&db, common::user(1, "alice", 0)).await.unwrap();
+
+    let model = user::ActiveModel {
+        id: ActiveValue::Unchanged(1),
+        status: ActiveValue::Set(5),
+        ..Default::default()
+    };
+    let patched = repo.patch_active(&db, model).await.unwrap();
+
+    assert_eq!(patched.name, "alice");
+    assert_eq!(patched.status, 5);
+}
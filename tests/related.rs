@@ -0,0 +1,106 @@
+mod common;
+
+use common::{player, team};
+use rust_framework::dto::request::PageQueryParam;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+
+async fn seeded() -> sea_orm::DatabaseConnection {
+    let db = common::setup().await;
+    let teams = GenericRepo::<team::Entity, i64>::new();
+    let players = GenericRepo::<player::Entity, i64>::new();
+    for (id, name) in [(1, "red"), (2, "blue"), (3, "green")] {
+        teams
+            .create(
+                &db,
+                team::Model {
+                    id,
+                    name: name.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+    }
+    for (id, team_id, name) in [
+        (1, Some(1), "ann"),
+        (2, Some(3), "ben"),
+        (3, Some(1), "cat"),
+        (4, None, "dan"),
+    ] {
+        players
+            .create(
+                &db,
+                player::Model {
+                    id,
+                    team_id,
+                    name: name.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+    }
+    db
+}
+
+fn names(players: &[player::Model]) -> Vec<&str> {
+    players.iter().map(|p| p.name.as_str()).collect()
+}
+
+#[tokio::test]
+async fn find_by_id_with_loads_has_many_and_belongs_to() {
+    let db = seeded().await;
+    let teams = GenericRepo::<team::Entity, i64>::new();
+    let players = GenericRepo::<player::Entity, i64>::new();
+
+    let (red, members) = teams
+        .find_by_id_with(&db, 1, player::Entity)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(red.name, "red");
+    assert_eq!(names(&members), ["ann", "cat"]);
+
+    let (ben, team) = players
+        .find_by_id_with(&db, 2, team::Entity)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(ben.name, "ben");
+    assert_eq!(team[0].name, "green");
+
+    let (_, none) = players
+        .find_by_id_with(&db, 4, team::Entity)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(none.is_empty());
+    assert!(teams
+        .find_by_id_with(&db, 9, player::Entity)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn find_page_with_keeps_page_order_and_groups_related_rows() {
+    let db = seeded().await;
+    let teams = GenericRepo::<team::Entity, i64>::new();
+    let param = PageQueryParam {
+        page_num: 0,
+        page_size: 2,
+        sort: Some("-id".to_string()),
+        ..Default::default()
+    };
+
+    let page = teams
+        .find_page_with(&db, &param, player::Entity)
+        .await
+        .unwrap();
+    assert_eq!(page.total, Some(3));
+    let rows: Vec<(&str, Vec<&str>)> = page
+        .data
+        .iter()
+        .map(|(team, players)| (team.name.as_str(), names(players)))
+        .collect();
+    assert_eq!(rows, [("green", vec!["ben"]), ("blue", vec![])]);
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use common::user;
+use rust_framework::db::{DbRouter, ReadConnection};
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::service::generic_service::GenericService;
+use rust_framework::service::service::Service;
+
+type UserService = GenericService<user::Entity, i64, GenericRepo<user::Entity, i64>>;
+
+// 主库和副本是两个独立的内存库，便于区分读写落在哪个连接上
+async fn router() -> DbRouter {
+    DbRouter::new(common::setup().await).with_reader(common::setup().await)
+}
+
+#[tokio::test]
+async fn reads_go_to_replica_and_writes_to_primary() {
+    let router = router().await;
+    let users = UserService::new(GenericRepo::new()).with_connection(router.clone());
+
+    users.create(common::user(1, "alice", 0)).await.unwrap();
+
+    assert!(users.find_by_id(1).await.unwrap().is_none());
+    assert_eq!(users.count().await.unwrap(), 0);
+    assert!(users
+        .service()
+        .find_by_id(router.writer(), 1)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn connected_repo_routes_reads_to_replica() {
+    let router = router().await;
+    let users = GenericRepo::<user::Entity, i64>::new().with_connection(router.clone());
+
+    users.create(common::user(1, "alice", 0)).await.unwrap();
+
+    assert!(!users.exists_by_id(1).await.unwrap());
+    assert_eq!(
+        users.update(common::user(1, "bob", 0)).await.unwrap().name,
+        "bob"
+    );
+}
+
+#[tokio::test]
+async fn explicit_router_argument_uses_primary() {
+    let router = router().await;
+    let service = UserService::new(GenericRepo::new());
+    service
+        .create(&router, common::user(1, "alice", 0))
+        .await
+        .unwrap();
+
+    assert!(service.find_by_id(&router, 1).await.unwrap().is_some());
+    assert!(service
+        .find_by_id(router.reader(), 1)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn read_your_writes_reads_from_primary() {
+    let router = router().await;
+    let users = UserService::new(GenericRepo::new()).with_connection(router.read_your_writes());
+
+    users.create(common::user(1, "alice", 0)).await.unwrap();
+
+    assert!(users.find_by_id(1).await.unwrap().is_some());
+    assert_eq!(users.count().await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn transaction_reads_see_own_writes() {
+    let router = router().await;
+    let service = UserService::new(GenericRepo::new());
+
+    let found: Result<bool, FrameworkError> = service
+        .transaction(&router, |txn, svc| {
+            Box::pin(async move {
+                svc.create(txn, common::user(1, "alice", 0)).await?;
+                svc.exists_by_id(txn, 1).await
+            })
+        })
+        .await;
+
+    assert!(found.unwrap());
+    assert!(service
+        .find_by_id(router.writer(), 1)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn router_without_replicas_reads_primary() {
+    let users =
+        UserService::new(GenericRepo::new()).with_connection(DbRouter::new(common::setup().await));
+
+    users.create(common::user(1, "alice", 0)).await.unwrap();
+
+    assert!(users.find_by_id(1).await.unwrap().is_some());
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use common::user;
+use rust_framework::dto::request::{SearchCondition, SearchRequest};
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use serde_json::json;
+use utoipa::ToSchema;
+
+async fn seeded() -> (sea_orm::DatabaseConnection, GenericRepo<user::Entity, i64>) {
+    let db = common::setup().await;
+    let repo = GenericRepo::new().with_filters();
+    for (id, name, status) in [
+        (1, "alice", 1),
+        (2, "alina", 2),
+        (3, "bob", 1),
+        (4, "carol", 3),
+        (5, "lisa", 2),
+    ] {
+        repo.create(&db, common::user(id, name, status))
+            .await
+            .unwrap();
+    }
+    (db, repo)
+}
+
+fn request(filter: serde_json::Value) -> SearchRequest {
+    serde_json::from_value(json!({
+        "page_num": 0,
+        "page_size": 10,
+        "sort": "-id",
+        "filter": filter,
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn search_compiles_nested_groups() {
+    let (db, repo) = seeded().await;
+    let req = request(json!({
+        "op": "and",
+        "conditions": [
+            { "op": "in", "field": "status", "values": [1, "2"] },
+            { "op": "or", "conditions": [
+                { "op": "like", "field": "name", "value": "li" },
+                { "op": "eq", "field": "id", "value": 3 }
+            ] },
+            { "op": "not", "condition": { "op": "eq", "field": "name", "value": "alina" } }
+        ]
+    }));
+
+    let page = repo.search(&db, &req).await.unwrap();
+    let ids: Vec<i64> = page.data.iter().map(|u| u.id).collect();
+    assert_eq!(ids, [5, 3, 1]);
+    assert_eq!(page.total, Some(3));
+}
+
+#[tokio::test]
+async fn search_supports_between_and_is_null() {
+    let (db, repo) = seeded().await;
+    let between = request(json!({ "op": "between", "field": "status", "from": 2, "to": 3 }));
+    let ids: Vec<i64> = repo
+        .search(&db, &between)
+        .await
+        .unwrap()
+        .data
+        .iter()
+        .map(|u| u.id)
+        .collect();
+    assert_eq!(ids, [5, 4, 2]);
+
+    let is_null = request(json!({ "op": "is_null", "field": "name" }));
+    assert!(repo.search(&db, &is_null).await.unwrap().data.is_empty());
+}
+
+#[tokio::test]
+async fn search_like_treats_wildcards_in_value_literally() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new().with_filters();
+    for (id, name) in [(1, "50%off"), (2, "500ff"), (3, "a_b"), (4, "axb")] {
+        repo.create(&db, common::user(id, name, 0)).await.unwrap();
+    }
+
+    let ids = |value: &str| {
+        let req = request(json!({ "op": "like", "field": "name", "value": value }));
+        let repo = &repo;
+        let db = &db;
+        async move {
+            let page = repo.search(db, &req).await.unwrap();
+            page.data.iter().map(|u| u.id).collect::<Vec<i64>>()
+        }
+    };
+    assert_eq!(ids("0%o").await, [1]);
+    assert_eq!(ids("_").await, [3]);
+}
+
+#[test]
+fn search_condition_reports_every_invalid_leaf() {
+    let repo = GenericRepo::<user::Entity, i64>::new().with_filters();
+    let condition: SearchCondition = serde_json::from_value(json!({
+        "op": "or",
+        "conditions": [
+            { "op": "eq", "field": "email", "value": "a@example.com" },
+            { "op": "gt", "field": "status", "value": "high" },
+            { "op": "like", "field": "status", "value": "1" },
+            { "op": "in", "field": "id", "values": [1, null] }
+        ]
+    }))
+    .unwrap();
+
+    match repo.search_condition(&condition).unwrap_err() {
+        FrameworkError::Validation { errors } => {
+            let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+            assert_eq!(
+                fields,
+                [
+                    "filter.email",
+                    "filter.status",
+                    "filter.status",
+                    "filter.id"
+                ]
+            );
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn search_request_exposes_openapi_schema() {
+    let (name, schema) = SearchCondition::schema();
+    assert_eq!(name, "SearchCondition");
+    let schema = serde_json::to_value(schema).unwrap();
+    assert_eq!(schema["oneOf"].as_array().unwrap().len(), 13);
+
+    let (name, schema) = SearchRequest::schema();
+    assert_eq!(name, "SearchRequest");
+    let schema = serde_json::to_value(schema).unwrap();
+    assert_eq!(
+        schema["properties"]["filter"]["allOf"][0]["$ref"],
+        "#/components/schemas/SearchCondition"
+    );
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use common::post;
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use rust_framework::repo::upsert::UpsertConflict;
+use sea_orm::{ColumnTrait, Condition};
+
+fn repo() -> GenericRepo<post::Entity, i64> {
+    GenericRepo::new().with_soft_delete()
+}
+
+#[tokio::test]
+async fn delete_marks_rows_and_hides_them_from_finds() {
+    let db = common::setup().await;
+    let repo = repo();
+    for id in 1..=3 {
+        repo.create(&db, common::post(id, "hello")).await.unwrap();
+    }
+
+    let result = repo.delete(&db, 1).await.unwrap();
+    assert_eq!(result.rows_affected, 1);
+    let result = repo
+        .delete_batch(&db, post::Column::Id.eq(2))
+        .await
+        .unwrap();
+    assert_eq!(result.rows_affected, 1);
+
+    assert!(repo.find_by_id(&db, 1).await.unwrap().is_none());
+    let ids: Vec<_> = repo
+        .find_list(&db)
+        .await
+        .unwrap()
+        .iter()
+        .map(|m| m.id)
+        .collect();
+    assert_eq!(ids, [3]);
+
+    let all = repo.find_with_deleted(&db, Condition::all()).await.unwrap();
+    assert_eq!(all.len(), 3);
+    assert!(all.iter().find(|m| m.id == 1).unwrap().deleted_at.is_some());
+}
+
+#[tokio::test]
+async fn restore_and_purge() {
+    let db = common::setup().await;
+    let repo = repo();
+    repo.create(&db, common::post(1, "hello")).await.unwrap();
+    repo.create(&db, common::post(2, "world")).await.unwrap();
+    repo.delete(&db, 1).await.unwrap();
+
+    assert_eq!(repo.restore(&db, 1).await.unwrap(), 1);
+    assert_eq!(repo.restore(&db, 2).await.unwrap(), 0);
+    assert!(repo.find_by_id(&db, 1).await.unwrap().is_some());
+
+    assert_eq!(repo.purge(&db, 2).await.unwrap().rows_affected, 1);
+    let all = repo.find_with_deleted(&db, Condition::all()).await.unwrap();
+    assert_eq!(all.iter().map(|m| m.id).collect::<Vec<_>>(), [1]);
+}
+
+#[tokio::test]
+async fn restore_requires_soft_delete() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<post::Entity, i64>::new();
+    repo.create(&db, common::post(1, "hello")).await.unwrap();
+
+    assert!(repo.restore(&db, 1).await.is_err());
+    assert_eq!(repo.delete(&db, 1).await.unwrap().rows_affected, 1);
+    assert!(repo
+        .find_with_deleted(&db, Condition::all())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn updates_skip_soft_deleted_rows() {
+    let db = common::setup().await;
+    let repo = repo();
+    let deleted = repo.create(&db, common::post(1, "hello")).await.unwrap();
+    repo.delete(&db, 1).await.unwrap();
+
+    let rows = repo
+        .update_by_condition(
+            &db,
+            post::Column::Id.eq(1),
+            vec![(post::Column::Title, "changed".into())],
+        )
+        .await
+        .unwrap();
+    assert_eq!(rows, 0);
+
+    let err = repo.update(&db, deleted.clone()).await.unwrap_err();
+    assert!(matches!(err, FrameworkError::NotFound(_)), "{:?}", err);
+
+    let conflict = UpsertConflict::primary_key::<post::Entity>();
+    let err = repo.upsert(&db, deleted, &conflict).await.unwrap_err();
+    assert!(matches!(err, FrameworkError::NotFound(_)), "{:?}", err);
+
+    let all = repo.find_with_deleted(&db, Condition::all()).await.unwrap();
+    assert_eq!(all[0].title, "hello");
+}
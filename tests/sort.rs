@@ -0,0 +1,166 @@
+mod common;
+
+use common::user;
+use rust_framework::dto::request::{Direction, PageQueryParam, SortKey};
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use sea_orm::ColumnTrait;
+
+fn page(sort_by: &str, sort_direction: Direction) -> PageQueryParam {
+    PageQueryParam {
+        page_num: 0,
+        page_size: 10,
+        sort_by: Some(sort_by.to_string()),
+        sort_direction: Some(sort_direction),
+        ..Default::default()
+    }
+}
+
+async fn seed(repo: &GenericRepo<user::Entity, i64>, db: &sea_orm::DatabaseConnection) {
+    for (id, name) in [(1, "carol"), (2, "alice"), (3, "bob")] {
+        repo.create(db, common::user(id, name, 0)).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn find_page_sorts_by_entity_column() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    seed(&repo, &db).await;
+
+    let page = repo
+        .find_page(&db, &page("name", Direction::DESC))
+        .await
+        .unwrap();
+    let names: Vec<_> = page.data.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(page.total, Some(3));
+    assert_eq!(names, ["carol", "bob", "alice"]);
+}
+
+#[tokio::test]
+async fn find_page_sorts_by_alias() {
+    let db = common::setup().await;
+    let repo =
+        GenericRepo::<user::Entity, i64>::new().with_sort_alias("userName", user::Column::Name);
+    seed(&repo, &db).await;
+
+    let page = repo
+        .find_page(&db, &page("userName", Direction::ASC))
+        .await
+        .unwrap();
+    let names: Vec<_> = page.data.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, ["alice", "bob", "carol"]);
+}
+
+#[tokio::test]
+async fn find_page_rejects_unknown_sort_field() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    seed(&repo, &db).await;
+
+    assert!(repo
+        .resolve_sort_column("(SELECT 1); DROP TABLE users")
+        .is_err());
+    let result = repo
+        .find_page(&db, &page("id; DROP TABLE users", Direction::ASC))
+        .await;
+    assert!(result.is_err());
+    assert_eq!(repo.find_list(&db).await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn find_page_condition_sorts_with_primary_key_tiebreak() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    for (id, status) in [(4, 1), (2, 1), (5, 0), (1, 1), (3, 1)] {
+        let name = format!("user{}", id);
+        repo.create(&db, common::user(id, &name, status))
+            .await
+            .unwrap();
+    }
+
+    let mut ids = Vec::new();
+    for page_num in 0..2 {
+        let param = PageQueryParam {
+            page_num,
+            page_size: 2,
+            ..page("status", Direction::ASC)
+        };
+        let page = repo
+            .find_page_condition(&db, user::Column::Status.eq(1), &param)
+            .await
+            .unwrap();
+        assert_eq!(page.total, Some(4));
+        assert_eq!(page.has_next, page_num == 0);
+        ids.extend(page.data.iter().map(|m| m.id));
+    }
+    assert_eq!(ids, [1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn find_page_sorts_by_multiple_keys() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    for (id, name, status) in [
+        (1, "alice", 1),
+        (2, "bob", 0),
+        (3, "carol", 1),
+        (4, "dave", 0),
+    ] {
+        repo.create(&db, common::user(id, name, status))
+            .await
+            .unwrap();
+    }
+
+    let param = PageQueryParam {
+        sort: Some("status, -name".to_string()),
+        ..page("id", Direction::ASC)
+    };
+    let page = repo.find_page(&db, &param).await.unwrap();
+    let ids: Vec<_> = page.data.iter().map(|m| m.id).collect();
+    assert_eq!(ids, [4, 2, 3, 1]);
+}
+
+#[tokio::test]
+async fn find_page_can_skip_total_count() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    seed(&repo, &db).await;
+
+    let mut param = PageQueryParam {
+        page_size: 2,
+        skip_total: true,
+        ..page("id", Direction::ASC)
+    };
+    let first = repo.find_page(&db, &param).await.unwrap();
+    assert_eq!(first.total, None);
+    assert!(first.has_next);
+    assert_eq!(first.data.iter().map(|m| m.id).collect::<Vec<_>>(), [1, 2]);
+
+    param.page_num = 1;
+    let second = repo.find_page(&db, &param).await.unwrap();
+    assert!(!second.has_next);
+    assert_eq!(second.data.iter().map(|m| m.id).collect::<Vec<_>>(), [3]);
+}
+
+#[test]
+fn sort_keys_parse_direction_prefixes() {
+    let keys = SortKey::parse_list("status,-created_at, +name,");
+    assert_eq!(
+        keys,
+        [
+            SortKey {
+                field: "status".to_string(),
+                direction: Direction::ASC
+            },
+            SortKey {
+                field: "created_at".to_string(),
+                direction: Direction::DESC
+            },
+            SortKey {
+                field: "name".to_string(),
+                direction: Direction::ASC
+            },
+        ]
+    );
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::{membership, post};
+use futures::TryStreamExt;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use sea_orm::{ColumnTrait, Condition};
+
+async fn seed_posts(db: &sea_orm::DatabaseConnection, repo: &GenericRepo<post::Entity, i64>) {
+    for id in 1..=7 {
+        repo.create(db, common::post(id, &format!("post{id}")))
+            .await
+            .unwrap();
+    }
+    repo.delete(db, 4).await.unwrap();
+}
+
+#[tokio::test]
+async fn stream_condition_yields_filtered_rows() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<post::Entity, i64>::new().with_soft_delete();
+    seed_posts(&db, &repo).await;
+
+    let posts: Vec<post::Model> = repo
+        .stream_condition(&db, post::Column::Id.gt(2))
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    let ids: Vec<i64> = posts.iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![3, 5, 6, 7]);
+}
+
+#[tokio::test]
+async fn stream_chunked_condition_walks_every_batch() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<post::Entity, i64>::new().with_soft_delete();
+    seed_posts(&db, &repo).await;
+
+    for chunk_size in [1, 2, 3, 6, 10] {
+        let posts: Vec<post::Model> = repo
+            .stream_chunked_condition(&db, Condition::all(), chunk_size)
+            .try_collect()
+            .await
+            .unwrap();
+        let ids: Vec<i64> = posts.iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![1, 2, 3, 5, 6, 7], "chunk size {chunk_size}");
+    }
+}
+
+#[tokio::test]
+async fn stream_chunked_condition_supports_composite_keys() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<membership::Entity, (i64, i64)>::new();
+    for (user_id, group_id) in [(2, 10), (1, 20), (1, 10), (3, 5)] {
+        repo.create(&db, common::membership(user_id, group_id, "member"))
+            .await
+            .unwrap();
+    }
+
+    let keys: Vec<(i64, i64)> = repo
+        .stream_chunked_condition(&db, Condition::all(), 3)
+        .map_ok(|m| (m.user_id, m.group_id))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(keys, vec![(1, 10), (1, 20), (2, 10), (3, 5)]);
+}
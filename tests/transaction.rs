@@ -0,0 +1,81 @@
+mod common;
+
+use common::user;
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::service::generic_service::GenericService;
+use rust_framework::service::service::Service;
+use sea_orm::{ColumnTrait, Value};
+
+type UserService = GenericService<user::Entity, i64, GenericRepo<user::Entity, i64>>;
+
+fn service() -> UserService {
+    GenericService::new(GenericRepo::new())
+}
+
+#[tokio::test]
+async fn transaction_commits_on_ok() {
+    let db = common::setup().await;
+    let service = service();
+
+    let result: Result<u64, FrameworkError> = service
+        .transaction(&db, |txn, svc| {
+            Box::pin(async move {
+                svc.create(txn, common::user(1, "alice", 0)).await?;
+                svc.update_by_condition(
+                    txn,
+                    user::Column::Id.eq(1),
+                    vec![(user::Column::Status, Value::from(1))],
+                )
+                .await
+            })
+        })
+        .await;
+
+    assert_eq!(result.unwrap(), 1);
+    let saved = service.find_by_id(&db, 1).await.unwrap().unwrap();
+    assert_eq!(saved.status, 1);
+}
+
+#[tokio::test]
+async fn transaction_rolls_back_on_err() {
+    let db = common::setup().await;
+    let service = service();
+
+    let result: Result<(), FrameworkError> = service
+        .transaction(&db, |txn, svc| {
+            Box::pin(async move {
+                svc.create(txn, common::user(1, "alice", 0)).await?;
+                svc.update_by_condition(
+                    txn,
+                    user::Column::Id.eq(1),
+                    vec![(user::Column::Status, Value::from(1))],
+                )
+                .await?;
+                Err(FrameworkError::forbidden("abort"))
+            })
+        })
+        .await;
+
+    assert!(result.is_err());
+    assert!(service.find_by_id(&db, 1).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn transaction_rolls_back_on_db_error() {
+    let db = common::setup().await;
+    let service = service();
+
+    let result: Result<user::Model, FrameworkError> = service
+        .transaction(&db, |txn, svc| {
+            Box::pin(async move {
+                svc.create(txn, common::user(1, "alice", 0)).await?;
+                // 主键冲突，整个事务回滚
+                svc.create(txn, common::user(1, "bob", 0)).await
+            })
+        })
+        .await;
+
+    assert!(matches!(result, Err(FrameworkError::Conflict(_))));
+    assert!(service.find_list(&db).await.unwrap().is_empty());
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use common::{document, user};
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::batch::RowOutcome;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+
+#[tokio::test]
+async fn update_many_saves_each_model() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    let mut alice = repo.create(&db, common::user(1, "alice", 0)).await.unwrap();
+    let mut bob = repo.create(&db, common::user(2, "bob", 0)).await.unwrap();
+
+    alice.status = 1;
+    bob.name = "robert".to_string();
+    let outcomes = repo
+        .update_many(&db, vec![alice.clone(), bob.clone()])
+        .await
+        .unwrap();
+
+    assert!(outcomes.iter().all(RowOutcome::is_updated));
+    assert_eq!(repo.find_by_id(&db, 1).await.unwrap(), Some(alice));
+    assert_eq!(repo.find_by_id(&db, 2).await.unwrap(), Some(bob));
+}
+
+#[tokio::test]
+async fn update_many_reports_failed_rows_and_rolls_back() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<document::Entity, i64>::new().with_versioning();
+    let mut first = repo.create(&db, common::document(1, "one")).await.unwrap();
+    let mut stale = repo.create(&db, common::document(2, "two")).await.unwrap();
+    // 另一个请求先更新了第二行，手上的版本号已过期
+    repo.update(&db, stale.clone()).await.unwrap();
+
+    first.content = "uno".to_string();
+    stale.content = "dos".to_string();
+    let outcomes = repo.update_many(&db, vec![first, stale]).await.unwrap();
+
+    assert!(matches!(outcomes[0], RowOutcome::RolledBack));
+    assert!(matches!(
+        outcomes[1],
+        RowOutcome::Failed(FrameworkError::VersionConflict(_))
+    ));
+    let unchanged = repo.find_by_id(&db, 1).await.unwrap().unwrap();
+    assert_eq!(unchanged.content, "one");
+    assert_eq!(unchanged.version, 0);
+}
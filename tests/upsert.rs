@@ -0,0 +1,114 @@
+mod common;
+
+use common::{document, user};
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use rust_framework::repo::upsert::UpsertConflict;
+use sea_orm::{DbBackend, EntityTrait, IntoActiveModel, QueryTrait};
+
+#[tokio::test]
+async fn upsert_inserts_then_updates_by_primary_key() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    let conflict = UpsertConflict::primary_key::<user::Entity>();
+
+    let inserted = repo
+        .upsert(&db, common::user(1, "alice", 0), &conflict)
+        .await
+        .unwrap();
+    assert_eq!(inserted.name, "alice");
+
+    let updated = repo
+        .upsert(&db, common::user(1, "alicia", 2), &conflict)
+        .await
+        .unwrap();
+    assert_eq!(updated.name, "alicia");
+    assert_eq!(updated.status, 2);
+    assert_eq!(repo.find_list(&db).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn upsert_by_unique_column_only_updates_listed_columns() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    repo.create(&db, common::user(1, "alice", 0)).await.unwrap();
+
+    // 同一 email 以新的 id 同步过来，只更新 status
+    let conflict =
+        UpsertConflict::columns([user::Column::Email]).update_columns([user::Column::Status]);
+    let mut incoming = common::user(7, "ignored", 4);
+    incoming.email = "alice@example.com".to_string();
+    let upserted = repo.upsert(&db, incoming, &conflict).await.unwrap();
+
+    assert_eq!(upserted.id, 1);
+    assert_eq!(upserted.name, "alice");
+    assert_eq!(upserted.status, 4);
+}
+
+#[tokio::test]
+async fn upsert_do_nothing_keeps_existing_row() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    repo.create(&db, common::user(1, "alice", 0)).await.unwrap();
+
+    let conflict = UpsertConflict::primary_key::<user::Entity>().do_nothing();
+    let existing = repo
+        .upsert(&db, common::user(1, "alicia", 2), &conflict)
+        .await
+        .unwrap();
+    assert_eq!(existing.name, "alice");
+}
+
+#[tokio::test]
+async fn upsert_many_mixes_inserts_and_updates() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<user::Entity, i64>::new();
+    repo.create(&db, common::user(1, "alice", 0)).await.unwrap();
+
+    let conflict = UpsertConflict::primary_key::<user::Entity>();
+    repo.upsert_many(
+        &db,
+        vec![common::user(1, "alicia", 1), common::user(2, "bob", 1)],
+        &conflict,
+    )
+    .await
+    .unwrap();
+
+    let users = repo.find_list(&db).await.unwrap();
+    assert_eq!(users.len(), 2);
+    assert!(users.iter().all(|u| u.status == 1));
+    assert_eq!(repo.upsert_many(&db, vec![], &conflict).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn upsert_bumps_version_on_conflict() {
+    let db = common::setup().await;
+    let repo = GenericRepo::<document::Entity, i64>::new().with_versioning();
+    let conflict = UpsertConflict::primary_key::<document::Entity>();
+    repo.create(&db, common::document(1, "draft"))
+        .await
+        .unwrap();
+
+    let updated = repo
+        .upsert(&db, common::document(1, "final"), &conflict)
+        .await
+        .unwrap();
+    assert_eq!(updated.content, "final");
+    assert_eq!(updated.version, 1);
+}
+
+#[test]
+fn on_conflict_builds_for_sqlite_and_postgres() {
+    let conflict =
+        UpsertConflict::columns([user::Column::Email]).update_columns([user::Column::Name]);
+    let sql = |backend| {
+        user::Entity::insert(common::user(1, "alice", 0).into_active_model())
+            .on_conflict(conflict.on_conflict::<user::Entity>())
+            .build(backend)
+            .to_string()
+    };
+    let expected = r#"ON CONFLICT ("email") DO UPDATE SET "name" = "excluded"."name""#;
+
+    assert!(sql(DbBackend::Sqlite).ends_with(expected));
+    assert!(sql(DbBackend::Postgres).ends_with(expected));
+}
@@ -0,0 +1,147 @@
+mod common;
+
+use common::document;
+use rust_framework::error::FrameworkError;
+use rust_framework::repo::generic_repo::GenericRepo;
+use rust_framework::repo::repo::Repo;
+use sea_orm::{ColumnTrait, Value};
+
+fn repo() -> GenericRepo<document::Entity, i64> {
+    GenericRepo::new().with_versioning()
+}
+
+#[tokio::test]
+async fn update_increments_version() {
+    let db = common::setup().await;
+    let repo = repo();
+    let mut model = repo
+        .create(&db, common::document(1, "draft"))
+        .await
+        .unwrap();
+
+    model.content = "final".to_string();
+    let updated = repo.update(&db, model).await.unwrap();
+    assert_eq!(updated.content, "final");
+    assert_eq!(updated.version, 1);
+}
+
+#[tokio::test]
+async fn stale_update_is_rejected() {
+    let db = common::setup().await;
+    let repo = repo();
+    let original = repo
+        .create(&db, common::document(1, "draft"))
+        .await
+        .unwrap();
+
+    let mut first = original.clone();
+    first.content = "first".to_string();
+    repo.update(&db, first).await.unwrap();
+
+    let mut second = original;
+    second.content = "second".to_string();
+    let err = repo.update(&db, second).await.unwrap_err();
+    assert!(
+        matches!(err, FrameworkError::VersionConflict(_)),
+        "{:?}",
+        err
+    );
+
+    let saved = repo.find_by_id(&db, 1).await.unwrap().unwrap();
+    assert_eq!(saved.content, "first");
+}
+
+#[tokio::test]
+async fn update_of_missing_row_is_not_found() {
+    let db = common::setup().await;
+    let err = repo()
+        .update(&db, common::document(1, "ghost"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, FrameworkError::NotFound(_)), "{:?}", err);
+}
+
+#[tokio::test]
+async fn update_by_condition_bumps_version() {
+    let db = common::setup().await;
+    let repo = repo();
+    repo.create(&db, common::document(1, "draft"))
+        .await
+        .unwrap();
+
+    repo.update_by_condition(
+        &db,
+        document::Column::Id.eq(1),
+        vec![(document::Column::Content, Value::from("bulk"))],
+    )
+    .await
+    .unwrap();
+    let saved = repo.find_by_id(&db, 1).await.unwrap().unwrap();
+    assert_eq!(saved.version, 1);
+}
+
+#[tokio::test]
+async fn stale_patch_is_rejected() {
+    let db = common::setup().await;
+    let repo = repo();
+    repo.create(&db, common::document(1, "draft"))
+        .await
+        .unwrap();
+    repo.patch(
+        &db,
+        1,
+        serde_json::json!({"content": "first", "version": 0}),
+    )
+    .await
+    .unwrap();
+
+    // 客户端仍持有版本 0
+    let result = repo
+        .patch(
+            &db,
+            1,
+            serde_json::json!({"content": "second", "version": 0}),
+        )
+        .await;
+    assert!(matches!(result, Err(FrameworkError::VersionConflict(_))));
+
+    let stale = document::ActiveModel {
+        id: sea_orm::ActiveValue::Unchanged(1),
+        content: sea_orm::ActiveValue::Set("third".to_string()),
+        version: sea_orm::ActiveValue::Unchanged(0),
+    };
+    let result = repo.patch_active(&db, stale).await;
+    assert!(matches!(result, Err(FrameworkError::VersionConflict(_))));
+
+    let saved = repo.find_by_id(&db, 1).await.unwrap().unwrap();
+    assert_eq!((saved.content.as_str(), saved.version), ("first", 1));
+}
+
+#[tokio::test]
+async fn patch_without_version_is_rejected() {
+    let db = common::setup().await;
+    let repo = repo();
+    repo.create(&db, common::document(1, "draft"))
+        .await
+        .unwrap();
+
+    let result = repo
+        .patch(&db, 1, serde_json::json!({"content": "edited"}))
+        .await;
+    assert!(matches!(
+        result,
+        Err(FrameworkError::Validation { errors }) if errors[0].field == "version"
+    ));
+
+    let result = repo
+        .patch_active(
+            &db,
+            document::ActiveModel {
+                id: sea_orm::ActiveValue::Unchanged(1),
+                content: sea_orm::ActiveValue::Set("edited".to_string()),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(FrameworkError::Validation { .. })));
+}